@@ -6,7 +6,7 @@ pub fn process(input: &str) -> Result<String> {
         let code = get_code(line);
         total_code += code;
     }
-    Ok(format!("final code: {total_code}"))
+    Ok(total_code.to_string())
 }
 
 fn get_code(code: &str) -> u32 {
@@ -42,4 +42,9 @@ mod tests {
         // Arrange / Act / Assert
         assert_eq!(get_code(input), expected);
     }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        util::examples::check(env!("CARGO_MANIFEST_DIR"), 1, process)
+    }
 }
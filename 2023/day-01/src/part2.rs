@@ -6,7 +6,7 @@ pub fn process(input: &str) -> Result<String> {
         let code = get_code(line);
         total_code += code;
     }
-    Ok(format!("final code: {total_code}"))
+    Ok(total_code.to_string())
 }
 
 // 52859 too high
@@ -81,4 +81,9 @@ mod tests {
     fn test_get_code_part2(#[case] input: &str, #[case] expected: u32) {
         assert_eq!(get_code(input), expected);
     }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        util::examples::check(env!("CARGO_MANIFEST_DIR"), 2, process)
+    }
 }
@@ -1,5 +1,9 @@
 use day_03::*;
 
+// counts allocations alongside the timings
+#[global_allocator]
+static ALLOC: divan::AllocProfiler = divan::AllocProfiler::system();
+
 fn main() {
     // Run registered benchmarks.
     divan::main();
@@ -1,2 +1,25 @@
+use anyhow::Result;
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day;
+
+impl util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part1(input: &&str) -> Result<String> {
+        part1::process(input)
+    }
+
+    fn part2(input: &&str) -> Result<String> {
+        part2::process(input)
+    }
+}
@@ -37,7 +37,7 @@ pub struct Card<'a> {
     pub winning: Vec<&'a str>,
 }
 
-pub fn parse_card(input: &str) -> IResult<&str, Card> {
+pub fn parse_card(input: &str) -> IResult<&str, Card<'_>> {
     let (input, id) = preceded(tag("Card"), preceded(space1, digit1))(input)?;
     let (input, (yours, winning)) = preceded(
         tag(":"),
@@ -53,7 +53,7 @@ pub fn parse_card(input: &str) -> IResult<&str, Card> {
 
     Ok((input, Card { id, yours, winning }))
 }
-pub fn parse_cards(input: &str) -> IResult<&str, Vec<Card>> {
+pub fn parse_cards(input: &str) -> IResult<&str, Vec<Card<'_>>> {
     let (input, cards) = separated_list1(line_ending, parse_card)(input)?;
     Ok((input, cards))
 }
@@ -1,2 +1,26 @@
+use anyhow::Result;
+
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day;
+
+impl util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = parse::Almanac;
+
+    fn parse(input: &str) -> Result<parse::Almanac> {
+        parse::parse(input)
+    }
+
+    fn part1(parsed: &parse::Almanac) -> Result<String> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &parse::Almanac) -> Result<String> {
+        part2::process(parsed)
+    }
+}
@@ -0,0 +1,115 @@
+use std::ops::Range;
+
+use anyhow::Result;
+use nom::{
+    bytes::complete::take_until,
+    character::complete::{self, line_ending, space1},
+    multi::{many1, separated_list1},
+    sequence::tuple,
+    IResult, Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Almanac {
+    /// Seeds for part 1, `start length` pairs of seed ranges for part 2.
+    pub seeds: Vec<u64>,
+    /// The maps in the order they apply, seed-to-soil through humidity-to-location.
+    pub entries: Vec<Entry>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub ranges: Vec<(Range<u64>, Range<u64>)>,
+}
+
+impl Almanac {
+    pub fn location(&self, seed: u64) -> u64 {
+        self.entries
+            .iter()
+            .fold(seed, |acc, entry| entry.get_dest(acc))
+    }
+}
+
+impl Entry {
+    fn get_dest(&self, source: u64) -> u64 {
+        let valid = self.ranges.iter().find(|(sr, _)| sr.contains(&source));
+
+        let Some((sr, dr)) = valid else {
+            return source;
+        };
+
+        let offset = source - sr.start;
+        dr.start + offset
+    }
+}
+
+pub fn parse(input: &str) -> Result<Almanac> {
+    let (_, almanac) = parse_almanac(input).map_err(|e| e.to_owned())?;
+    Ok(almanac)
+}
+
+pub fn parse_seeds(input: &str) -> IResult<&str, Vec<u64>> {
+    tag("seeds: ")
+        .precedes(separated_list1(space1, complete::u64))
+        .parse(input)
+}
+
+pub fn parse_entry(input: &str) -> IResult<&str, Entry> {
+    take_until("map:")
+        .precedes(tag("map:"))
+        .precedes(
+            many1(
+                line_ending.precedes(
+                    tuple((
+                        complete::u64,
+                        complete::u64.preceded_by(tag(" ")),
+                        complete::u64.preceded_by(tag(" ")),
+                    ))
+                    .map(|(dest, source, len)| (source..source + len, dest..dest + len)),
+                ),
+            )
+            .map(|ranges| Entry { ranges }),
+        )
+        .parse(input)
+}
+
+pub fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
+    let (input, seeds) = parse_seeds(input)?;
+    let (input, entries) = many1(parse_entry)(input)?;
+    Ok((input, Almanac { seeds, entries }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_seeds() -> Result<()> {
+        let input = "seeds: 79 14 55 13";
+        let (_, result) = parse_seeds(input)?;
+        assert_eq!(vec![79, 14, 55, 13], result);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_entry() -> Result<()> {
+        // Arrange
+        let input = "seed-to-soil map:
+50 98 2
+52 50 5
+
+";
+        let expected = Entry {
+            ranges: vec![(98..100, 50..52), (50..55, 52..57)],
+        };
+
+        // Act
+        let (_, result) = parse_entry(input)?;
+
+        // Assert
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -27,7 +27,7 @@ fn parse_node(input: &str) -> IResult<&str, (&str, (&str, &str))> {
     )(input)
 }
 
-fn parse_map(input: &str) -> IResult<&str, Map> {
+fn parse_map(input: &str) -> IResult<&str, Map<'_>> {
     let (o, map) = separated_pair(
         alpha1,
         tag("\n\n"),
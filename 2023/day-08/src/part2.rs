@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use nom::{
     bytes::complete::tag,
     character::complete::{alpha1, alphanumeric1, line_ending},
@@ -8,6 +8,10 @@ use nom::{
     sequence::{delimited, separated_pair},
     IResult,
 };
+use util::{
+    cycle::{self, Cycle},
+    math,
+};
 
 #[derive(Debug)]
 pub struct Map<'a> {
@@ -27,7 +31,7 @@ fn parse_node(input: &str) -> IResult<&str, (&str, (&str, &str))> {
     )(input)
 }
 
-fn parse_map(input: &str) -> IResult<&str, Map> {
+fn parse_map(input: &str) -> IResult<&str, Map<'_>> {
     let (o, map) = separated_pair(
         alpha1,
         tag("\n\n"),
@@ -53,66 +57,114 @@ fn parse_map(input: &str) -> IResult<&str, Map> {
 pub fn process(input: &str) -> Result<String> {
     let (_, map) = parse_map(input).expect("valid parse");
 
-    let starting_nodes: Vec<&str> = map
+    // the real inputs loop each ghost straight back to its start, so the lcm of
+    // the first arrivals works for them, but in general a loop can begin later
+    // and pass several goals, which `Ghost` keeps track of
+    let ghosts = map
         .nodes
         .keys()
         .filter(|n| n.ends_with('A'))
-        .cloned()
-        .collect();
-
-    let instructions = map.instructions;
+        .map(|node| Ghost::walk(&map, node))
+        .collect::<Vec<_>>();
 
-    // Needed help to get this one.
-    let result = starting_nodes
-        .iter()
-        .map(|node| {
-            let mut starting_nodes_visited = vec![*node];
-            let mut current_node = *node;
-            instructions
-                .iter()
-                .cycle()
-                .enumerate()
-                .find_map(|(index, instruction)| {
-                    let option = map
-                        .nodes
-                        .get(current_node)
-                        .expect("should always have a node");
-
-                    let next_node = match instruction {
-                        'L' => option.0,
-                        'R' => option.1,
-                        _ => unreachable!("should never have anything other than LR"),
-                    };
-
-                    if next_node.ends_with('Z') {
-                        Some(index + 1)
-                    } else {
-                        starting_nodes_visited.push(next_node);
-                        current_node = next_node;
-                        None
-                    }
-                })
-                .expect("should find cycle")
-        })
-        .collect::<Vec<usize>>();
+    first_meeting(&ghosts)
+        .map(|step| step.to_string())
+        .ok_or_else(|| anyhow!("the ghosts are never all on a goal at once"))
+}
 
-    Ok(lcm(&result).to_string())
+/// When one ghost stands on a node ending in `Z`.
+#[derive(Debug, PartialEq)]
+struct Ghost {
+    /// Goal steps before its loop starts, they do not come round again.
+    tail: Vec<usize>,
+    /// Goal steps in the first lap of its loop.
+    lap: Vec<usize>,
+    cycle: Cycle,
 }
 
-pub fn lcm(nums: &[usize]) -> usize {
-    if nums.len() == 1 {
-        return nums[0];
+impl Ghost {
+    /// Follows a ghost from `start` until it is back at the same node at the
+    /// same point in the instructions.
+    fn walk<'a>(map: &Map<'a>, start: &'a str) -> Self {
+        let step = |(node, at): &mut (&'a str, usize)| {
+            let (left, right) = map.nodes.get(*node).expect("should always have a node");
+            *node = match map.instructions[*at] {
+                'L' => left,
+                'R' => right,
+                _ => unreachable!("should never have anything other than LR"),
+            };
+            *at = (*at + 1) % map.instructions.len();
+        };
+
+        let cycle = cycle::brent(&(start, 0), step);
+        let mut state = (start, 0);
+        let (mut tail, mut lap) = (vec![], vec![]);
+        for n in 0..cycle.start + cycle.period {
+            if state.0.ends_with('Z') {
+                if n < cycle.start {
+                    tail.push(n);
+                } else {
+                    lap.push(n);
+                }
+            }
+            step(&mut state);
+        }
+        Ghost { tail, lap, cycle }
+    }
+
+    fn on_goal(&self, n: usize) -> bool {
+        let n = self.cycle.earliest(n);
+        self.tail.contains(&n) || self.lap.contains(&n)
     }
-    let a = nums[0];
-    let b = lcm(&nums[1..]);
-    a * b / gcd_of_two_numbers(a, b)
 }
 
-fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
-    if b == 0 {
-        return a;
+/// The first step every ghost is on a goal at.
+fn first_meeting(ghosts: &[Ghost]) -> Option<usize> {
+    // any step before the last loop starts is in the tail of that ghost
+    let tail = ghosts
+        .iter()
+        .flat_map(|ghost| &ghost.tail)
+        .filter(|&&n| ghosts.iter().all(|ghost| ghost.on_goal(n)))
+        .min();
+    if tail.is_some() {
+        return tail.copied();
+    }
+
+    // the steps every ghost so far is on a loop goal at, as `x mod m`, which
+    // each ghost narrows down by one of its goals at a time. They all share
+    // the same `m`, so there are never more of them than that.
+    let latest_start = ghosts.iter().map(|ghost| ghost.cycle.start).max()?;
+    let mut meetings = vec![(0, 1)];
+    for ghost in ghosts {
+        let period = i64::try_from(ghost.cycle.period).ok()?;
+        let goals = ghost
+            .lap
+            .iter()
+            .map(|&n| i64::try_from(n).ok())
+            .collect::<Option<Vec<_>>>()?;
+        meetings = meetings
+            .iter()
+            .flat_map(|&meeting| {
+                goals
+                    .iter()
+                    .filter_map(move |&goal| math::crt(&[meeting, (goal, period)]))
+            })
+            .collect();
+        meetings.sort_unstable();
+        meetings.dedup();
     }
-    gcd_of_two_numbers(b, a % b)
+    meetings
+        .into_iter()
+        .filter_map(|(x, m)| {
+            let (x, m) = (usize::try_from(x).ok()?, usize::try_from(m).ok()?);
+            // the smallest solution may still be in somebody's tail
+            latest_start
+                .saturating_sub(x)
+                .div_ceil(m)
+                .checked_mul(m)?
+                .checked_add(x)
+        })
+        .min()
 }
 
 #[cfg(test)]
@@ -136,4 +188,76 @@ XXX = (XXX, XXX)";
         assert_eq!(process(input)?, "6");
         Ok(())
     }
+
+    #[test]
+    fn test_process_offset_loops() -> Result<()> {
+        // goals on odd steps and on steps 2 more than a multiple of 3, which
+        // the lcm of the first arrivals, 2, does not satisfy
+        let input = "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22C, 22C)
+22C = (22B, 22B)";
+
+        assert_eq!(process(input)?, "5");
+        Ok(())
+    }
+
+    #[test]
+    fn test_first_meeting_many_goals() {
+        // one pick per ghost would be 3^20 sets of congruences to solve
+        let ghosts = (0..20)
+            .map(|_| Ghost {
+                tail: vec![],
+                lap: vec![2, 3, 4],
+                cycle: Cycle {
+                    start: 1,
+                    period: 4,
+                },
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(first_meeting(&ghosts), Some(2));
+
+        let mut ghosts = ghosts;
+        ghosts.push(Ghost {
+            tail: vec![],
+            lap: vec![5],
+            cycle: Cycle {
+                start: 5,
+                period: 4,
+            },
+        });
+        assert_eq!(first_meeting(&ghosts), None);
+    }
+
+    #[test]
+    fn test_walk() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        let (_, map) = parse_map(input).unwrap();
+
+        assert_eq!(
+            Ghost::walk(&map, "22A"),
+            Ghost {
+                tail: vec![],
+                lap: vec![3, 6],
+                cycle: Cycle {
+                    start: 1,
+                    period: 6
+                },
+            }
+        );
+    }
 }
@@ -1,4 +1,9 @@
 use day_09::*;
+use util::Solution;
+
+// counts allocations alongside the timings
+#[global_allocator]
+static ALLOC: divan::AllocProfiler = divan::AllocProfiler::system();
 
 fn main() {
     // Run registered benchmarks.
@@ -6,11 +11,19 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!("../input1.txt",))).unwrap();
+fn parse() {
+    Day::parse(divan::black_box(include_str!("../input1.txt",))).unwrap();
+}
+
+// the parts are timed on an already parsed input, `parse` above covers the rest
+#[divan::bench]
+fn part1(bencher: divan::Bencher) {
+    let parsed = Day::parse(include_str!("../input1.txt")).unwrap();
+    bencher.bench(|| part1::process(divan::black_box(&parsed)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!("../input2.txt",))).unwrap();
+fn part2(bencher: divan::Bencher) {
+    let parsed = Day::parse(include_str!("../input2.txt")).unwrap();
+    bencher.bench(|| part2::process(divan::black_box(&parsed)).unwrap());
 }
@@ -1,2 +1,26 @@
+use anyhow::Result;
+
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day;
+
+impl util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Parsed<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
+        parse::parse(input)
+    }
+
+    fn part1(parsed: &Vec<Vec<i64>>) -> Result<String> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Vec<Vec<i64>>) -> Result<String> {
+        part2::process(parsed)
+    }
+}
@@ -0,0 +1,20 @@
+use anyhow::Result;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    multi::separated_list1,
+    IResult,
+};
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
+    let (_, histories) = parse_histories(input).map_err(|e| e.to_owned())?;
+    Ok(histories)
+}
+
+pub fn parse_history(input: &str) -> IResult<&str, Vec<i64>> {
+    separated_list1(tag(" "), complete::i64)(input)
+}
+
+pub fn parse_histories(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
+    separated_list1(line_ending, parse_history)(input)
+}
@@ -1,27 +1,11 @@
 use anyhow::Result;
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, line_ending},
-    multi::separated_list1,
-    IResult,
-};
-
-pub fn parse_history(input: &str) -> IResult<&str, Vec<i64>> {
-    separated_list1(tag(" "), complete::i64)(input)
-}
-
-pub fn parse_histories(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
-    separated_list1(line_ending, parse_history)(input)
-}
 
 // 1062 = just right
-pub fn process(input: &str) -> Result<String> {
-    let (_, histories) = parse_histories(input).expect("should be valid parse");
-
+pub fn process(histories: &[Vec<i64>]) -> Result<String> {
     let ends: i64 = histories
-        .into_iter()
+        .iter()
         .fold(Vec::new(), |mut acc, hist| {
-            let mut ex_hist = expand_history(hist);
+            let mut ex_hist = expand_history(hist.clone());
             ex_hist.reverse();
             let inner_placeholders =
                 ex_hist
@@ -83,6 +67,7 @@ pub fn expand_history(input: Vec<i64>) -> Vec<Vec<i64>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::parse;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
@@ -143,7 +128,7 @@ mod tests {
         let input = "0 3 6 9 12 15
 1 3 6 10 15 21
 10 13 16 21 30 45";
-        assert_eq!(process(input)?, "2");
+        assert_eq!(process(&parse(input)?)?, "2");
         Ok(())
     }
 }
@@ -1,13 +1,7 @@
 use std::{collections::BTreeMap, fmt::Display};
 
 use anyhow::Result;
-
-const TABLE: &[Pos; 4] = &[
-    Pos { x: 0, y: -1 },
-    Pos { x: 1, y: 0 },
-    Pos { x: 0, y: 1 },
-    Pos { x: -1, y: 0 },
-];
+use util::geom::{Dir, Pos};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Node {
@@ -33,28 +27,6 @@ pub enum Tile {
     Start,
 }
 
-#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord)]
-pub struct Pos {
-    x: i32,
-    y: i32,
-}
-
-impl Pos {
-    fn new(x: i32, y: i32) -> Self {
-        Pos { x, y }
-    }
-
-    fn dir(&self) -> &Dir {
-        match self {
-            Pos { x, y } if x == &0 && y == &-1 => &Dir::N,
-            Pos { x, y } if x == &1 && y == &0 => &Dir::E,
-            Pos { x, y } if x == &0 && y == &1 => &Dir::S,
-            Pos { x, y } if x == &-1 && y == &0 => &Dir::W,
-            _ => unreachable!("not a vaild coord pair"),
-        }
-    }
-}
-
 pub fn build_maze(input: &str) -> (Node, Vec<Vec<Node>>) {
     let mut start: Node = Node {
         pos: Pos::new(0, 0),
@@ -71,14 +43,14 @@ pub fn build_maze(input: &str) -> (Node, Vec<Vec<Node>>) {
                     match tile {
                         Tile::Start => {
                             let node = Node {
-                                pos: Pos::new(x as i32, y as i32),
+                                pos: Pos::new(x as isize, y as isize),
                                 tile,
                             };
                             start = node.clone();
                             node
                         }
                         _ => Node {
-                            pos: Pos::new(x as i32, y as i32),
+                            pos: Pos::new(x as isize, y as isize),
                             tile,
                         },
                     }
@@ -99,7 +71,7 @@ pub fn process(input: &str) -> Result<String> {
     let mut map = BTreeMap::<Pos, Pos>::new();
 
     get_connections(&maze.0, &maze.1, &mut map);
-    let mut pos = maze.0.pos.clone();
+    let mut pos = maze.0.pos;
     let mut count = 1;
 
     while let Some(curr) = map.get(&pos) {
@@ -107,25 +79,25 @@ pub fn process(input: &str) -> Result<String> {
             break;
         }
         count += 1;
-        pos = curr.clone();
+        pos = *curr;
     }
 
     Ok((count as f32 / 2.0).round().to_string())
 }
 
 pub fn get_connections(start_node: &Node, maze: &Vec<Vec<Node>>, map: &mut BTreeMap<Pos, Pos>) {
-    TABLE.iter().for_each(|loc| {
-        let pos = Pos::new(start_node.pos.x + loc.x, start_node.pos.y + loc.y);
+    Dir::ALL.iter().for_each(|&dir| {
+        let pos = start_node.pos.step(dir);
         if let Some(row) = maze.get(pos.y as usize) {
             if let Some(node) = row.get(pos.x as usize) {
-                let can_connect = start_node.tile.can_connect(loc.dir(), &node.tile);
+                let can_connect = start_node.tile.can_connect(dir, &node.tile);
 
                 let node_is_visisted_from_start_node = map
                     .get(&node.pos)
                     .is_some_and(|node_pos| node_pos == &start_node.pos);
 
                 if node.tile == Tile::Start && can_connect && !node_is_visisted_from_start_node {
-                    map.insert(start_node.pos.clone(), node.pos.clone());
+                    map.insert(start_node.pos, node.pos);
                     return;
                 }
 
@@ -133,7 +105,7 @@ pub fn get_connections(start_node: &Node, maze: &Vec<Vec<Node>>, map: &mut BTree
                     && can_connect
                     && !node_is_visisted_from_start_node
                 {
-                    map.insert(start_node.pos.clone(), node.pos.clone());
+                    map.insert(start_node.pos, node.pos);
                     get_connections(node, maze, map);
                 }
             }
@@ -144,14 +116,15 @@ pub fn get_connections(start_node: &Node, maze: &Vec<Vec<Node>>, map: &mut BTree
 pub fn connected_pipe(start_node: &Node, check: &Pos, maze: &[Vec<Node>]) -> bool {
     if let Some(j_tiles) = maze.get((start_node.pos.y + check.y) as usize) {
         if let Some(ij_node) = j_tiles.get((start_node.pos.x + check.x) as usize) {
-            return start_node.tile.can_connect(check.dir(), &ij_node.tile);
+            return Dir::from_offset(*check)
+                .is_some_and(|dir| start_node.tile.can_connect(dir, &ij_node.tile));
         }
     }
     false
 }
 
 impl Tile {
-    fn can_connect(&self, dir: &Dir, other: &Tile) -> bool {
+    fn can_connect(&self, dir: Dir, other: &Tile) -> bool {
         use Tile::*;
 
         //  N
@@ -159,39 +132,39 @@ impl Tile {
         //  S
         match self {
             NS => match dir {
-                Dir::N => vec![NS, SE90, SW90, Start].contains(other),
-                Dir::S => vec![NS, NE90, NW90, Start].contains(other),
+                Dir::N => [NS, SE90, SW90, Start].contains(other),
+                Dir::S => [NS, NE90, NW90, Start].contains(other),
                 Dir::E => false,
                 Dir::W => false,
             },
             EW => match dir {
                 Dir::N => false,
                 Dir::S => false,
-                Dir::E => vec![EW, SW90, NW90, Start].contains(other),
-                Dir::W => vec![EW, SE90, NE90, Start].contains(other),
+                Dir::E => [EW, SW90, NW90, Start].contains(other),
+                Dir::W => [EW, SE90, NE90, Start].contains(other),
             },
             NE90 => match dir {
-                Dir::N => vec![NS, SW90, SE90, Start].contains(other),
+                Dir::N => [NS, SW90, SE90, Start].contains(other),
                 Dir::S => false,
-                Dir::E => vec![EW, NW90, SW90, Start].contains(other),
+                Dir::E => [EW, NW90, SW90, Start].contains(other),
                 Dir::W => false,
             },
             NW90 => match dir {
-                Dir::N => vec![NS, SW90, SE90, Start].contains(other),
+                Dir::N => [NS, SW90, SE90, Start].contains(other),
                 Dir::S => false,
                 Dir::E => false,
-                Dir::W => vec![EW, SE90, NE90, Start].contains(other),
+                Dir::W => [EW, SE90, NE90, Start].contains(other),
             },
             SW90 => match dir {
                 Dir::N => false,
-                Dir::S => vec![NS, NE90, NW90, Start].contains(other),
+                Dir::S => [NS, NE90, NW90, Start].contains(other),
                 Dir::E => false,
-                Dir::W => vec![EW, NE90, SE90, Start].contains(other),
+                Dir::W => [EW, NE90, SE90, Start].contains(other),
             },
             SE90 => match dir {
                 Dir::N => false,
-                Dir::S => vec![NS, NE90, NW90, Start].contains(other),
-                Dir::E => vec![EW, NW90, SW90, Start].contains(other),
+                Dir::S => [NS, NE90, NW90, Start].contains(other),
+                Dir::E => [EW, NW90, SW90, Start].contains(other),
                 Dir::W => false,
             },
             G => match dir {
@@ -201,10 +174,10 @@ impl Tile {
                 Dir::W => false,
             },
             Start => match dir {
-                Dir::N => vec![NS, SW90, SE90].contains(other),
-                Dir::S => vec![NS, NW90, NE90].contains(other),
-                Dir::E => vec![EW, NW90, SW90].contains(other),
-                Dir::W => vec![EW, NE90, SE90].contains(other),
+                Dir::N => [NS, SW90, SE90].contains(other),
+                Dir::S => [NS, NW90, NE90].contains(other),
+                Dir::E => [EW, NW90, SW90].contains(other),
+                Dir::W => [EW, NE90, SE90].contains(other),
             },
         }
     }
@@ -247,13 +220,6 @@ impl From<char> for Tile {
     }
 }
 
-pub enum Dir {
-    N,
-    S,
-    E,
-    W,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,7 +236,7 @@ mod tests {
         #[case] input: (Tile, Dir, Tile),
         #[case] expected: bool,
     ) -> Result<()> {
-        assert_eq!(input.0.can_connect(&input.1, &input.2), expected);
+        assert_eq!(input.0.can_connect(input.1, &input.2), expected);
         Ok(())
     }
 
@@ -84,8 +84,7 @@ pub fn expand_rows(image: &[Vec<Tile>]) -> Vec<Vec<Tile>> {
             if rows > 0 {
                 for _ in 0..(rows * 2) {
                     acc.push(
-                        std::iter::repeat(Tile::Space)
-                            .take(row.len())
+                        std::iter::repeat_n(Tile::Space, row.len())
                             .collect::<Vec<Tile>>(),
                     );
                 }
@@ -110,12 +109,12 @@ pub fn process(input: &str) -> Result<String> {
 
     println!(
         "(pre)space: {}x{}",
-        image.get(0).unwrap().len(),
+        image.first().unwrap().len(),
         image.len()
     );
     println!(
         "(exp)space: {}x{}",
-        expanded.get(0).unwrap().len(),
+        expanded.first().unwrap().len(),
         expanded.len()
     );
 
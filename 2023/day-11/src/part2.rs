@@ -92,8 +92,7 @@ pub fn expand_rows(image: &[Vec<Tile>], expand_by: i64) -> (Vec<Vec<Tile>>, Vec<
                     });
                     for _ in 0..rows {
                         acc.push(
-                            std::iter::repeat(Tile::AllSpace)
-                                .take(row.len())
+                            std::iter::repeat_n(Tile::AllSpace, row.len())
                                 .collect::<Vec<Tile>>(),
                         );
                     }
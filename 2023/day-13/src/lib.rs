@@ -1,2 +1,26 @@
+use anyhow::Result;
+
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day;
+
+impl util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Parsed<'a> = Vec<Vec<String>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<String>>> {
+        parse::parse(input)
+    }
+
+    fn part1(parsed: &Vec<Vec<String>>) -> Result<String> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Vec<Vec<String>>) -> Result<String> {
+        part2::process(parsed)
+    }
+}
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::line_ending,
+    multi::separated_list1,
+    IResult,
+};
+
+pub fn parse(input: &str) -> Result<Vec<Vec<String>>> {
+    let (_, patterns) = parse_patterns(input).map_err(|e| e.to_owned())?;
+    Ok(patterns)
+}
+
+pub fn parse_patterns(input: &str) -> IResult<&str, Vec<Vec<String>>> {
+    separated_list1(tag("\n\n"), parse_pattern)(input)
+}
+
+pub fn parse_pattern(input: &str) -> IResult<&str, Vec<String>> {
+    let (o, pattern) = separated_list1(line_ending, take_till1(|c| c != '.' && c != '#'))(input)?;
+
+    Ok((
+        o,
+        pattern
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<String>>(),
+    ))
+}
@@ -1,15 +1,7 @@
 use anyhow::Result;
-use nom::{
-    bytes::complete::{tag, take_till1},
-    character::complete::line_ending,
-    multi::separated_list1,
-    IResult,
-};
 
 // 37561 = just right
-pub fn process(input: &str) -> Result<String> {
-    let (_, patterns) = parse_patterns(input).expect("valid parse");
-
+pub fn process(patterns: &[Vec<String>]) -> Result<String> {
     let result = patterns.iter().fold(0, |mut acc, pattern| {
         acc += check_horizontal_vertical(pattern);
         acc
@@ -18,22 +10,6 @@ pub fn process(input: &str) -> Result<String> {
     Ok(result.to_string())
 }
 
-pub fn parse_patterns(input: &str) -> IResult<&str, Vec<Vec<String>>> {
-    separated_list1(tag("\n\n"), parse_pattern)(input)
-}
-
-pub fn parse_pattern(input: &str) -> IResult<&str, Vec<String>> {
-    let (o, pattern) = separated_list1(line_ending, take_till1(|c| c != '.' && c != '#'))(input)?;
-
-    Ok((
-        o,
-        pattern
-            .iter()
-            .map(|p| p.to_string())
-            .collect::<Vec<String>>(),
-    ))
-}
-
 pub fn transpose_image(image: &[Vec<char>]) -> Vec<Vec<char>> {
     let ncols = image[0].len();
     (0..ncols)
@@ -105,6 +81,7 @@ fn check_horizontal_vertical(pattern: &[String]) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::{parse, parse_pattern};
     use pretty_assertions::assert_eq;
     use rstest::*;
 
@@ -162,7 +139,7 @@ mod tests {
 #####.##.
 ..##..###
 #....#..#";
-        assert_eq!(process(input)?, "405");
+        assert_eq!(process(&parse(input)?)?, "405");
         Ok(())
     }
 }
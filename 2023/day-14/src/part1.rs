@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 use anyhow::Result;
-use util::transpose_matrix;
+use util::Grid;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Copy)]
 pub enum Tile {
@@ -39,29 +39,19 @@ impl From<char> for Tile {
 
 // 113456 = just right
 pub fn process(input: &str) -> Result<String> {
-    let platform = input
-        .lines()
-        .map(|line| line.chars().map(Tile::from).collect())
-        .collect::<Vec<Vec<_>>>();
+    let mut platform = input.parse::<Grid<Tile>>()?;
 
-    let mut transposed = transpose_matrix(&platform);
-
-    let sorted = transposed
-        .iter_mut()
-        .map(|row| {
-            row.split_mut(|c| c == &Tile::R)
-                .for_each(|section| section.sort());
-            row.clone()
-        })
-        .collect::<Vec<_>>();
-
-    let transposed = &transpose_matrix(&sorted);
+    // the columns roll north, so they are sorted as the rows of the transposed platform
+    platform.transpose();
+    for row in platform.rows_mut() {
+        row.split_mut(|c| c == &Tile::R)
+            .for_each(|section| section.sort());
+    }
 
-    let len = sorted.len() - 1;
-    let load = transposed.iter().enumerate().fold(0, |mut acc, (i, row)| {
-        let stones = row.iter().filter(|c| c == &&Tile::O).count();
-        acc += (len - i + 1) * stones;
-        acc
+    let north_up = platform.view().transposed();
+    let load = north_up.rows().enumerate().fold(0, |acc, (i, row)| {
+        let stones = row.filter(|c| c == &&Tile::O).count();
+        acc + (north_up.height() - i) * stones
     });
 
     Ok(load.to_string())
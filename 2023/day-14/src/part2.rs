@@ -1,9 +1,9 @@
-use std::{collections::HashMap, fmt::Display};
+use std::fmt::Display;
 
 use anyhow::Result;
-use util::transpose_matrix;
+use util::{cycle, Grid};
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Copy)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Copy, Hash)]
 pub enum Tile {
     O,
     R,
@@ -41,125 +41,50 @@ impl From<char> for Tile {
 // 118780 = too high
 // 118747 = just right
 pub fn process(input: &str) -> Result<String> {
-    let cycles = 1_000_000_000;
-
-    let load = run_cycle(input, cycles);
-
-    Ok(load.0)
+    let platform = input.parse::<Grid<Tile>>()?;
+    let platform = cycle::hashed_nth(&platform, spin, 1_000_000_000);
+    Ok(north_load(&platform).to_string())
 }
 
-pub fn run_cycle(input: &str, cycles: i32) -> (String, String) {
-    let mut platform = input
-        .lines()
-        .map(|line| line.chars().map(Tile::from).collect())
-        .collect::<Vec<Vec<_>>>();
-
-    let mut cycle_cache: HashMap<String, (usize, Vec<Vec<Tile>>)> = HashMap::new();
-
-    let mut remaining_cycles = 0;
-    let mut cycle_count = 0;
+/// Tilts the platform north, west, south and then east.
+pub fn spin(platform: &mut Grid<Tile>) {
+    // north and south roll along the columns, the rows of the transposed platform
+    platform.transpose();
+    roll(platform, false);
+    platform.transpose();
+    roll(platform, false);
+    platform.transpose();
+    roll(platform, true);
+    platform.transpose();
+    roll(platform, true);
+}
 
-    for i in 0..cycles {
-        let platform_string = platform
-            .iter()
-            .map(|row| row.iter().map(|c| c.to_char()).collect::<String>())
-            .collect::<Vec<String>>()
-            .join("\n");
+fn north_load(platform: &Grid<Tile>) -> usize {
+    let len = platform.height();
+    platform
+        .rows()
+        .enumerate()
+        .map(|(i, row)| (len - i) * row.iter().filter(|c| c == &&Tile::O).count())
+        .sum()
+}
 
-        if remaining_cycles == 0 {
-            if let Some(hit) = cycle_cache.get(&platform_string) {
-                let start_index = hit.0 as i32;
-                remaining_cycles = (1_000_000_000 - start_index) % (i - start_index);
-                println!(
-                    "cycle: {}, start: {}, period: {}",
-                    i, hit.0, remaining_cycles
-                );
+/// Rolls the round rocks of every row up against the cube rocks, to the
+/// start of the row or, with `to_end`, to its end.
+fn roll(platform: &mut Grid<Tile>, to_end: bool) {
+    for row in platform.rows_mut() {
+        for section in row.split_mut(|c| c == &Tile::R) {
+            let rocks = section.iter().filter(|c| c == &&Tile::O).count();
+            if to_end {
+                let (space, rolled) = section.split_at_mut(section.len() - rocks);
+                space.fill(Tile::S);
+                rolled.fill(Tile::O);
             } else {
-                cycle_cache.insert(platform_string, (i as usize, platform.clone()));
+                let (rolled, space) = section.split_at_mut(rocks);
+                rolled.fill(Tile::O);
+                space.fill(Tile::S);
             }
-        } else if cycle_count == remaining_cycles - 1 {
-            println!(
-                "i: {}, period: {}, count: {}",
-                i, remaining_cycles, cycle_count
-            );
-            break;
-        } else if cycle_count < remaining_cycles {
-            cycle_count += 1;
-            println!(
-                "i: {}, period: {}, count: {}",
-                i, remaining_cycles, cycle_count
-            );
         }
-
-        platform = transpose_matrix(&platform);
-        platform = platform
-            .iter_mut()
-            .map(|row| {
-                row.split_mut(|c| c == &Tile::R)
-                    .for_each(|section| section.sort());
-                row.clone()
-            })
-            .collect::<Vec<_>>();
-
-        platform = transpose_matrix(&platform);
-        // println!("post-north---");
-        // print_matrix(&platform);
-        // println!("---");
-
-        platform = platform
-            .iter_mut()
-            .map(|row| {
-                row.split_mut(|c| c == &Tile::R)
-                    .for_each(|section| section.sort());
-                row.clone()
-            })
-            .collect::<Vec<_>>();
-
-        // println!("post-west---");
-        // print_matrix(&platform);
-        // println!("---");
-
-        platform = transpose_matrix(&platform);
-
-        platform = platform
-            .iter_mut()
-            .map(|row| {
-                row.split_mut(|c| c == &Tile::R)
-                    .for_each(|section| section.sort_by(|a, b| b.cmp(a)));
-                row.clone()
-            })
-            .collect::<Vec<_>>();
-
-        platform = transpose_matrix(&platform);
-        // println!("post-south---");
-        // print_matrix(&platform);
-        // println!("---");
-
-        platform = platform
-            .iter_mut()
-            .map(|row| {
-                row.split_mut(|c| c == &Tile::R)
-                    .for_each(|section| section.sort_by(|a, b| b.cmp(a)));
-                row.clone()
-            })
-            .collect::<Vec<_>>();
     }
-
-    let len = platform.len();
-    let load = platform.iter().enumerate().fold(0, |mut acc, (i, row)| {
-        let stones = row.iter().filter(|c| c == &&Tile::O).count();
-        acc += (len - i) * stones;
-        acc
-    });
-
-    (
-        load.to_string(),
-        platform
-            .iter()
-            .map(|row| row.iter().map(|c| c.to_char()).collect::<String>())
-            .collect::<Vec<String>>()
-            .join("\n"),
-    )
 }
 
 #[cfg(test)]
@@ -208,7 +133,7 @@ mod tests {
 #.OOO#...O",
         3
     )]
-    fn test_run_cycle(#[case] expected: &str, #[case] cycles: i32) -> Result<()> {
+    fn test_spin(#[case] expected: &str, #[case] cycles: usize) -> Result<()> {
         let input = "O....#....
 O.OO#....#
 .....##...
@@ -220,12 +145,9 @@ O.#..O.#.#
 #....###..
 #OO..#....";
 
-        let result = run_cycle(input, cycles);
-        println!("---------");
-        println!("input:\n{}\n---", input);
-        println!("expected:\n{}\n---", expected);
-        println!("result:\n{}\n---", result.1);
-        assert_eq!(result.1, expected);
+        let platform = input.parse::<Grid<Tile>>()?;
+        let platform = cycle::nth(&platform, spin, cycles);
+        assert_eq!(platform.to_string(), expected);
         Ok(())
     }
 
@@ -27,8 +27,8 @@ pub struct Pos {
 
 #[derive(Debug)]
 pub struct ELoc {
-    pos: Pos,
-    dir: Dir,
+    pub pos: Pos,
+    pub dir: Dir,
 }
 
 pub fn process(input: &str) -> Result<String> {
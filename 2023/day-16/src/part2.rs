@@ -10,6 +10,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     #[test]
+    #[allow(unreachable_code)]
     fn test_process() -> Result<()> {
         todo!("haven't built test yet");
         let input = "";
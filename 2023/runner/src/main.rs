@@ -0,0 +1,114 @@
+use anyhow::{anyhow, Context, Result};
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use clap::{Args, Parser, Subcommand};
+
+use self::registry::registry;
+
+mod registry;
+
+const YEAR: u16 = 2023;
+
+#[cfg(feature = "alloc")]
+#[global_allocator]
+static ALLOC: util::alloc::Counting = util::alloc::Counting;
+
+#[derive(Parser)]
+#[command(author, version, about)]
+struct RunnerCli {
+    #[command(subcommand)]
+    command: RunnerCommands,
+}
+
+#[derive(Subcommand)]
+enum RunnerCommands {
+    /// list every registered day and part
+    List,
+    Run(RunArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    day: u8,
+    part: u8,
+    /// file to read the puzzle input from, `-` reads stdin. defaults to the day's input file
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// print how long the part took to stderr
+    #[arg(short, long)]
+    time: bool,
+}
+
+fn main() -> Result<()> {
+    let cli = RunnerCli::parse();
+    let registry = registry();
+
+    match cli.command {
+        RunnerCommands::List => {
+            for entry in registry.entries() {
+                println!("{} day-{:02} part{}", entry.year, entry.day, entry.part);
+            }
+        }
+        RunnerCommands::Run(args) => {
+            let entry = registry
+                .get(YEAR, args.day, args.part)
+                .ok_or_else(|| anyhow!("day {} part {} is not registered", args.day, args.part))?;
+            let input = read_input(
+                &args
+                    .input
+                    .unwrap_or_else(|| input_path(args.day, args.part)),
+            )?;
+
+            #[cfg(feature = "alloc")]
+            util::alloc::reset();
+            let start = Instant::now();
+            let result = (entry.run)(&input)
+                .with_context(|| format!("process day {} part {}", args.day, args.part))?;
+            let elapsed = start.elapsed();
+            #[cfg(feature = "alloc")]
+            {
+                let stats = util::alloc::stats();
+                eprintln!("allocations: {}", stats.allocations);
+                eprintln!("allocated bytes: {}", stats.bytes);
+                eprintln!("peak bytes: {}", stats.peak_bytes);
+            }
+
+            println!("{}", result);
+            if args.time {
+                eprintln!("elapsed: {elapsed:?}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn read_input(path: &Path) -> Result<String> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("read input from stdin")?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).with_context(|| format!("read input {}", path.display()))
+}
+
+fn input_path(day: u8, part: u8) -> PathBuf {
+    project_root()
+        .join(format!("day-{day:02}"))
+        .join(format!("input{part}.txt"))
+}
+
+fn project_root() -> PathBuf {
+    Path::new(&env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(1)
+        .unwrap()
+        .to_path_buf()
+}
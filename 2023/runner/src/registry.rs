@@ -0,0 +1,23 @@
+use util::solution::Registry;
+
+/// Every solved day. `cargo xtask generate` appends new days here.
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+    registry.register::<day_01::Day>();
+    registry.register::<day_02::Day>();
+    registry.register::<day_03::Day>();
+    registry.register::<day_04::Day>();
+    registry.register::<day_05::Day>();
+    registry.register::<day_06::Day>();
+    registry.register::<day_07::Day>();
+    registry.register::<day_08::Day>();
+    registry.register::<day_09::Day>();
+    registry.register::<day_10::Day>();
+    registry.register::<day_11::Day>();
+    registry.register::<day_12::Day>();
+    registry.register::<day_13::Day>();
+    registry.register::<day_14::Day>();
+    registry.register::<day_15::Day>();
+    registry.register::<day_16::Day>();
+    registry
+}
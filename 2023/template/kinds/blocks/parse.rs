@@ -0,0 +1,26 @@
+use anyhow::Result;
+
+/// The lines of every block, blocks are separated by a blank line.
+pub type Parsed<'a> = Vec<Vec<&'a str>>;
+
+pub fn parse(input: &str) -> Result<Parsed<'_>> {
+    Ok(input
+        .split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+        .map(|block| block.lines().collect())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let blocks = parse("#.\n.#\n\n##\n")?;
+        assert_eq!(blocks.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(blocks, vec![vec!["#.", ".#"], vec!["##"]]);
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use anyhow::Result;
+use util::{geom::Pos, Grid};
+
+/// One row per line, one cell per character.
+pub type Parsed<'a> = Grid<char>;
+
+pub fn parse(input: &str) -> Result<Parsed<'_>> {
+    input.parse()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let grid = parse("#..\n..#\n")?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.iter().filter(|(_, cell)| **cell == '#').count(), 2);
+        assert_eq!(grid[Pos::new(2, 1)], '#');
+        Ok(())
+    }
+}
@@ -1,21 +1,21 @@
 use anyhow::Result;
 
-pub fn process(
-    input: &str,
-) -> Result<String> {
-    todo!("{input}");
+use crate::parse::Parsed;
+
+pub fn process(parsed: &Parsed) -> Result<String> {
+    todo!("{parsed:?}");
 }
 
 #[cfg(test)]
 mod tests {
-    use pretty_assertions::assert_eq;
     use super::*;
+    use crate::parse::parse;
 
+    // add examples with `cargo xtask example add <day> <part> --expected <answer>`
     #[test]
-    fn test_process() -> Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!(process(input)?, "");
-        Ok(())
+    fn test_examples() -> Result<()> {
+        util::examples::check(env!("CARGO_MANIFEST_DIR"), 2, |input| {
+            process(&parse(input)?)
+        })
     }
 }
@@ -0,0 +1,599 @@
+use anyhow::{anyhow, bail, Result};
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::geom::Pos;
+
+/// A rectangular grid stored row after row in one `Vec`.
+///
+/// Cells are found by [`Pos`], `x` the column and `y` the row, `(0, 0)` the
+/// top left. Positions are signed so a neighbour off the edge is just a miss
+/// in [`Grid::get`] instead of an underflow.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which all have to be the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for row in rows {
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width != row.len() => {
+                    bail!(
+                        "row {height} is {} long, the rows above are {width}",
+                        row.len()
+                    )
+                }
+                Some(_) => {}
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        pos.to_index(self.width).filter(|&i| i < self.cells.len())
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every cell with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(i, cell)| {
+            let pos = Pos::from_index(i, width).expect("a cell should fit in a Pos");
+            (pos, cell)
+        })
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.inside(pos.neighbours4())
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.inside(pos.neighbours8())
+    }
+
+    fn inside(&self, positions: impl Iterator<Item = Pos>) -> impl Iterator<Item = (Pos, &T)> {
+        positions.filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// Row `y`, panics when it is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {y} is outside a grid {} high",
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    /// Column `x` from top to bottom, panics when it is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside a grid {} wide",
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The position of the first `tile`, reading row after row.
+    pub fn find(&self, tile: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == tile)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&mut self) {
+        for row in self.rows_mut() {
+            row.reverse();
+        }
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&mut self) {
+        let width = self.width;
+        for y in 0..self.height / 2 {
+            let (top, bottom) = self.cells.split_at_mut((self.height - 1 - y) * width);
+            top[y * width..(y + 1) * width].swap_with_slice(&mut bottom[..width]);
+        }
+    }
+
+    /// Swaps rows for columns, without reallocating even when the grid is
+    /// not square.
+    pub fn transpose(&mut self) {
+        let (width, height) = (self.width, self.height);
+        if width == height {
+            for y in 0..height {
+                for x in y + 1..width {
+                    self.cells.swap(y * width + x, x * width + y);
+                }
+            }
+        } else {
+            // the cell at `i` belongs at `next(i)`, which splits the cells into
+            // cycles that are each rotated once, from their smallest index
+            let last = self.cells.len().saturating_sub(1);
+            let next = |i: usize| i * height % last;
+            for start in 1..last {
+                let mut i = next(start);
+                while i > start {
+                    i = next(i);
+                }
+                if i < start {
+                    continue;
+                }
+                let mut i = next(start);
+                while i != start {
+                    self.cells.swap(start, i);
+                    i = next(i);
+                }
+            }
+        }
+        self.width = height;
+        self.height = width;
+    }
+
+    /// Turns the grid a quarter clockwise, in place.
+    pub fn rotate_clockwise(&mut self) {
+        self.transpose();
+        self.flip_horizontal();
+    }
+
+    /// Turns the grid a quarter counter-clockwise, in place.
+    pub fn rotate_counter_clockwise(&mut self) {
+        self.transpose();
+        self.flip_vertical();
+    }
+
+    /// Reads the grid without moving it, turn or mirror the view to read it
+    /// another way round.
+    pub fn view(&self) -> View<'_, T> {
+        View {
+            grid: self,
+            transposed: false,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+}
+
+/// Panics when `pos` is outside the grid, [`Grid::get`] does not.
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} is outside a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} is outside a {width}x{height} grid"),
+        }
+    }
+}
+
+/// A [`Grid`] read turned or mirrored, without copying it.
+///
+/// A position in the view is first mirrored by `flip_x` and `flip_y`, then
+/// swapped around when `transposed`, to find its cell in the grid.
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    transposed: bool,
+    flip_x: bool,
+    flip_y: bool,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        if self.transposed {
+            self.grid.height
+        } else {
+            self.grid.width
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        if self.transposed {
+            self.grid.width
+        } else {
+            self.grid.height
+        }
+    }
+
+    pub fn transposed(self) -> Self {
+        Self {
+            transposed: !self.transposed,
+            flip_x: self.flip_y,
+            flip_y: self.flip_x,
+            ..self
+        }
+    }
+
+    pub fn flipped_horizontal(self) -> Self {
+        Self {
+            flip_x: !self.flip_x,
+            ..self
+        }
+    }
+
+    pub fn flipped_vertical(self) -> Self {
+        Self {
+            flip_y: !self.flip_y,
+            ..self
+        }
+    }
+
+    pub fn rotated_clockwise(self) -> Self {
+        self.transposed().flipped_horizontal()
+    }
+
+    pub fn rotated_counter_clockwise(self) -> Self {
+        self.transposed().flipped_vertical()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&'a T> {
+        let x = usize::try_from(pos.x).ok().filter(|&x| x < self.width())?;
+        let y = usize::try_from(pos.y).ok().filter(|&y| y < self.height())?;
+        let x = if self.flip_x { self.width() - 1 - x } else { x };
+        let y = if self.flip_y {
+            self.height() - 1 - y
+        } else {
+            y
+        };
+        let (x, y) = if self.transposed { (y, x) } else { (x, y) };
+        Some(&self.grid.cells[y * self.grid.width + x])
+    }
+
+    pub fn row(self, y: usize) -> impl Iterator<Item = &'a T> {
+        assert!(
+            y < self.height(),
+            "row {y} is outside a view {} high",
+            self.height()
+        );
+        (0..self.width()).filter_map(move |x| self.get(Pos::new(x as isize, y as isize)))
+    }
+
+    pub fn rows(self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        (0..self.height()).map(move |y| self.row(y))
+    }
+
+    /// Copies the cells out in the order the view reads them.
+    pub fn to_grid(self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.width(),
+            height: self.height(),
+            cells: self.rows().flatten().cloned().collect(),
+        }
+    }
+}
+
+/// Parses a map of characters, one row per line, each character becoming a
+/// cell through its `TryFrom<char>`.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let rows = s
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| T::try_from(c).map_err(|e| anyhow!("({x}, {y}) `{c}`: {e}")))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_rows(rows)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_rows(f, self.rows())
+    }
+}
+
+impl<T: Display> Display for View<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_rows(f, self.rows())
+    }
+}
+
+fn fmt_rows<'a, T: Display + 'a>(
+    f: &mut fmt::Formatter<'_>,
+    rows: impl Iterator<Item = impl IntoIterator<Item = &'a T>>,
+) -> fmt::Result {
+    for (y, row) in rows.enumerate() {
+        if y > 0 {
+            writeln!(f)?;
+        }
+        for cell in row {
+            write!(f, "{cell}")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, PartialEq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = String;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                c => Err(format!("not a tile: {c}")),
+            }
+        }
+    }
+
+    fn example() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let grid = "#.\n.#\n".parse::<Grid<Tile>>()?;
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.get(Pos::new(1, 0)), Some(&Tile::Open));
+        assert_eq!(grid[Pos::new(1, 1)], Tile::Wall);
+
+        let err = "#.\n.x\n".parse::<Grid<Tile>>().unwrap_err();
+        assert_eq!(err.to_string(), "(1, 1) `x`: not a tile: x");
+        assert!("ab\nc\n".parse::<Grid<char>>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = example();
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Pos::new(-1, 0)), None);
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, 2)), None);
+        assert!(!grid.contains(Pos::new(0, -1)));
+
+        *grid.get_mut(Pos::new(0, 1)).unwrap() = 'x';
+        grid[Pos::new(2, 0)] = 'y';
+        assert_eq!(grid.to_string(), "aby\nxef");
+    }
+
+    #[test]
+    #[should_panic(expected = "Pos { x: 3, y: 0 } is outside a 3x2 grid")]
+    fn test_index_outside() {
+        let _ = &example()[Pos::new(3, 0)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>(),
+            vec![(Pos::new(1, 0), &'b'), (Pos::new(0, 1), &'d')]
+        );
+        assert_eq!(
+            grid.neighbours8(Pos::new(1, 0))
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "cfeda"
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = example();
+        assert_eq!(grid.find(&'e'), Some(Pos::new(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(
+            Grid::new(2, 1, 0).iter().collect::<Vec<_>>(),
+            vec![(Pos::new(0, 0), &0), (Pos::new(1, 0), &0)]
+        );
+    }
+
+    #[test]
+    fn test_flip() {
+        let mut grid = example();
+        grid.flip_horizontal();
+        assert_eq!(grid.to_string(), "cba\nfed");
+        grid.flip_vertical();
+        assert_eq!(grid.to_string(), "fed\ncba");
+
+        let mut tall = "ab\ncd\nef".parse::<Grid<char>>().unwrap();
+        tall.flip_vertical();
+        assert_eq!(tall.to_string(), "ef\ncd\nab");
+    }
+
+    #[test]
+    fn test_transpose() {
+        let mut grid = example();
+        grid.transpose();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.to_string(), "ad\nbe\ncf");
+        grid.transpose();
+        assert_eq!(grid, example());
+
+        let mut square = "abc\ndef\nghi".parse::<Grid<char>>().unwrap();
+        square.transpose();
+        assert_eq!(square.to_string(), "adg\nbeh\ncfi");
+
+        // each shape splits into its own cycles, so try a spread of them
+        for width in 1..8 {
+            for height in 1..8 {
+                let mut grid = Grid::from_rows((0..height).map(|y| {
+                    (0..width)
+                        .map(|x| Pos::new(x as isize, y as isize))
+                        .collect()
+                }))
+                .unwrap();
+                grid.transpose();
+                assert_eq!((grid.width(), grid.height()), (height, width));
+                for (pos, &from) in grid.iter() {
+                    assert_eq!(Pos::new(from.y, from.x), pos);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut grid = example();
+        grid.rotate_clockwise();
+        assert_eq!(grid.to_string(), "da\neb\nfc");
+        grid.rotate_counter_clockwise();
+        assert_eq!(grid, example());
+        for _ in 0..4 {
+            grid.rotate_counter_clockwise();
+        }
+        assert_eq!(grid, example());
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = example();
+        let view = grid.view().rotated_clockwise();
+        assert_eq!((view.width(), view.height()), (2, 3));
+        assert_eq!(view.get(Pos::new(1, 0)), Some(&'a'));
+        assert_eq!(view.get(Pos::new(2, 0)), None);
+        assert_eq!(view.to_string(), "da\neb\nfc");
+
+        // every way of turning the view reads like turning the grid itself
+        fn turn_view(view: View<'_, char>, turn: usize) -> View<'_, char> {
+            match turn {
+                0 => view.transposed(),
+                1 => view.flipped_horizontal(),
+                2 => view.flipped_vertical(),
+                3 => view.rotated_clockwise(),
+                _ => view.rotated_counter_clockwise(),
+            }
+        }
+        fn turn_grid(grid: &mut Grid<char>, turn: usize) {
+            match turn {
+                0 => grid.transpose(),
+                1 => grid.flip_horizontal(),
+                2 => grid.flip_vertical(),
+                3 => grid.rotate_clockwise(),
+                _ => grid.rotate_counter_clockwise(),
+            }
+        }
+        for first in 0..5 {
+            for then in 0..5 {
+                let mut turned = example();
+                turn_grid(&mut turned, first);
+                turn_grid(&mut turned, then);
+                assert_eq!(
+                    turn_view(turn_view(grid.view(), first), then).to_grid(),
+                    turned
+                );
+            }
+        }
+    }
+}
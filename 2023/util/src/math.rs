@@ -0,0 +1,225 @@
+use std::ops::{Div, Rem};
+
+/// The whole numbers [`gcd`] and [`lcm`] work on, signed or not.
+pub trait Integer: Copy + Ord + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// `None` for a signed type's `MIN`, always `Some` when unsigned.
+    fn checked_abs(self) -> Option<Self>;
+    /// `%` except that `MIN % -1` is `0` rather than an overflow.
+    fn wrapping_rem(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($abs:expr; $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    $abs(self)
+                }
+
+                fn wrapping_rem(self, other: Self) -> Self {
+                    <$t>::wrapping_rem(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(|n: Self| n.checked_abs(); i8, i16, i32, i64, i128, isize);
+impl_integer!(Some; u8, u16, u32, u64, u128, usize);
+
+/// The greatest common divisor, never negative. `None` only when that is
+/// `2^(bits - 1)`, one past a signed type's `MAX`.
+pub fn gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a.wrapping_rem(b));
+    }
+    a.checked_abs()
+}
+
+/// The least common multiple, never negative and `0` when either is.
+/// `None` when it does not fit.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+/// [`gcd`] of every number, `0` for none at all.
+pub fn gcd_all<T: Integer>(nums: &[T]) -> Option<T> {
+    nums.iter().try_fold(T::ZERO, |acc, &n| gcd(acc, n))
+}
+
+/// [`lcm`] of every number, `1` for none at all.
+pub fn lcm_all<T: Integer>(nums: &[T]) -> Option<T> {
+    nums.iter().try_fold(T::ONE, |acc, &n| lcm(acc, n))
+}
+
+/// `(g, x, y)` with `a * x + b * y == g`, `g` being the [`gcd`] of `a` and
+/// `b`. Panics when `g` does not fit, which takes an `i64::MIN` and a `0`
+/// or another `i64::MIN`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_wide(a.into(), b.into());
+    let narrow = |n: i128| i64::try_from(n).expect("gcd should fit in an i64");
+    (narrow(g), narrow(x), narrow(y))
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, `None` when `a` and `m` share
+/// a factor or `m` is not positive.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd_wide(a.into(), m.into());
+    (g == 1).then(|| x.rem_euclid(m.into()) as i64)
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` at once,
+/// giving the smallest `x` that is not negative and the lcm of the moduli,
+/// which every other solution differs from it by a multiple of.
+///
+/// The moduli do not have to be coprime. `None` when the congruences
+/// contradict each other, a modulus is not positive or the lcm does not fit.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (x, m) = congruences
+        .iter()
+        .try_fold((0_i128, 1_i128), |(r1, m1), &(r2, m2)| {
+            if m2 <= 0 {
+                return None;
+            }
+            let (r2, m2) = (i128::from(r2).rem_euclid(m2.into()), i128::from(m2));
+            let (g, p, _) = extended_gcd_wide(m1, m2);
+            if (r2 - r1) % g != 0 {
+                return None;
+            }
+            // x = r1 + m1 * k where m1 * k ≡ r2 - r1 (mod m2), p inverting m1 / g
+            let step = m2 / g;
+            let k = ((r2 - r1) / g).rem_euclid(step) * p.rem_euclid(step) % step;
+            let m = m1 * step;
+            if m > i64::MAX.into() {
+                return None;
+            }
+            Some(((r1 + m1 * k).rem_euclid(m), m))
+        })?;
+    Some((x as i64, m as i64))
+}
+
+/// [`extended_gcd`] with room to spare for anything made of `i64`s.
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12_u32, 18), Some(6));
+        assert_eq!(gcd(-12, 18), Some(6));
+        assert_eq!(gcd(0, -7), Some(7));
+        assert_eq!(gcd(0_u8, 0), Some(0));
+        assert_eq!(gcd(i64::MIN, -1), Some(1));
+        assert_eq!(gcd(i64::MIN, 0), None);
+        assert_eq!(gcd(u64::MAX, u64::MAX), Some(u64::MAX));
+
+        assert_eq!(lcm(4_usize, 6), Some(12));
+        assert_eq!(lcm(-4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(200_u8, 3), None);
+        assert_eq!(lcm(i32::MIN, 1), None);
+
+        assert_eq!(gcd_all(&[24, 36, -60]), Some(12));
+        assert_eq!(gcd_all::<i32>(&[]), Some(0));
+        assert_eq!(lcm_all(&[2_u64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all::<u64>(&[]), Some(1));
+        assert_eq!(lcm_all(&[u64::MAX, 2]), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for a in -30..30 {
+            for b in -30..30 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(Some(g), gcd(a, b), "{a}, {b}");
+                assert_eq!(a * x + b * y, g, "{a}, {b}");
+            }
+        }
+        let (g, x, y) = extended_gcd(i64::MAX, i64::MAX - 1);
+        assert_eq!(g, 1);
+        assert_eq!(
+            i128::from(i64::MAX) * i128::from(x) + i128::from(i64::MAX - 1) * i128::from(y),
+            1
+        );
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+        assert_eq!(mod_inverse(5, 0), None);
+        let m = 1_000_000_007;
+        let inverse = mod_inverse(123_456_789, m).unwrap();
+        assert_eq!(123_456_789 * inverse % m, 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(0, 0)]), None);
+        assert_eq!(crt(&[(0, i64::MAX), (0, 2)]), None);
+        assert_eq!(
+            crt(&[(1, 1_000_000_007), (2, 998_244_353)]),
+            Some((993_328_913_953_302_350, 998_244_359_987_710_471))
+        );
+
+        // against trying every x, for every pair of small moduli
+        for m1 in 1..13 {
+            for m2 in 1..13 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let m = lcm(m1, m2).unwrap();
+                        let expected = (0..m).find(|x| x % m1 == r1 && x % m2 == r2);
+                        assert_eq!(
+                            crt(&[(r1, m1), (r2, m2)]),
+                            expected.map(|x| (x, m)),
+                            "{r1} mod {m1}, {r2} mod {m2}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::Result;
+
+/// Signature shared by every part entry point.
+pub type PartFn = fn(&str) -> Result<String>;
+
+/// A single day's puzzle. Each day crate implements this once in its `lib.rs`
+/// so the runner can look it up instead of every day carrying its own bins.
+///
+/// The input is parsed once into [`Solution::Parsed`] and both parts solve
+/// from that, so the parsing is not written twice and can be timed on its own.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// What both parts work on, usually borrowing from the input. Days whose
+    /// parts still parse the input for themselves just pass it on as `&'a str`.
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<String>;
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<String>;
+
+    /// Parses `input` and solves part 1 from it, the way the runner calls it.
+    fn solve1(input: &str) -> Result<String> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    /// Parses `input` and solves part 2 from it, the way the runner calls it.
+    fn solve2(input: &str) -> Result<String> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub run: PartFn,
+}
+
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    pub fn register<S: Solution>(&mut self) -> &mut Self {
+        for (part, run) in [(1, S::solve1 as PartFn), (2, S::solve2 as PartFn)] {
+            self.entries.push(Entry {
+                year: S::YEAR,
+                day: S::DAY,
+                part,
+                run,
+            });
+        }
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|e| e.year == year && e.day == day && e.part == part)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    struct Day;
+
+    impl Solution for Day {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        type Parsed<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Vec<&str>> {
+            Ok(input.split(',').collect())
+        }
+
+        fn part1(parsed: &Vec<&str>) -> Result<String> {
+            Ok(parsed.len().to_string())
+        }
+
+        fn part2(parsed: &Vec<&str>) -> Result<String> {
+            Ok(parsed.join("").to_uppercase())
+        }
+    }
+
+    #[test]
+    fn test_register_adds_both_parts() -> Result<()> {
+        let mut registry = Registry::default();
+        registry.register::<Day>();
+
+        assert_eq!(registry.entries().len(), 2);
+        let part1 = registry.get(2015, 1, 1).expect("part1 to be registered");
+        assert_eq!((part1.run)("a,b,c")?, "3");
+        let part2 = registry.get(2015, 1, 2).expect("part2 to be registered");
+        assert_eq!((part2.run)("a,b,c")?, "ABC");
+        assert!(registry.get(2015, 2, 1).is_none());
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 package main
 
-import "testing"
+import (
+	"os"
+	"testing"
+)
 
 func TestFindDistanceSum(t *testing.T) {
 	input := `3   4
@@ -16,3 +19,13 @@ func TestFindDistanceSum(t *testing.T) {
 		t.Errorf("Expected 11, got %d", result)
 	}
 }
+
+func BenchmarkPart2(b *testing.B) {
+	input, err := os.ReadFile("../../input1.txt")
+	if err != nil {
+		b.Fatalf("unable to read input: %v", err)
+	}
+	for i := 0; i < b.N; i++ {
+		FindSimilarityScore(string(input))
+	}
+}
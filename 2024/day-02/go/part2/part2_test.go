@@ -1,6 +1,9 @@
 package main
 
-import "testing"
+import (
+	"os"
+	"testing"
+)
 
 func TestPart2(t *testing.T) {
   expected := 4
@@ -15,3 +18,13 @@ func TestPart2(t *testing.T) {
     t.Errorf("Expected %d, got %d", expected, result)
   }
 }
+
+func BenchmarkPart2(b *testing.B) {
+	input, err := os.ReadFile("../../input1.txt")
+	if err != nil {
+		b.Fatalf("unable to read input: %v", err)
+	}
+	for i := 0; i < b.N; i++ {
+		CountSafeReports(string(input))
+	}
+}
@@ -2,6 +2,7 @@ package main
 
 import (
 	"fmt"
+	"os"
 	"testing"
 )
 
@@ -14,3 +15,13 @@ func TestPart1(t *testing.T) {
     t.Errorf("Expected %d, got %d", expected, result)
   }
 }
+
+func BenchmarkPart1(b *testing.B) {
+  input, err := os.ReadFile("../../input1.txt")
+  if err != nil {
+    b.Fatalf("unable to read input: %v", err)
+  }
+  for i := 0; i < b.N; i++ {
+    ProcessMulInstructions(string(input))
+  }
+}
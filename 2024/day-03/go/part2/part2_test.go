@@ -2,6 +2,7 @@ package main
 
 import (
 	"fmt"
+	"os"
 	"testing"
 )
 
@@ -15,3 +16,12 @@ func TestPart2(t *testing.T) {
   }
 }
 
+func BenchmarkPart2(b *testing.B) {
+  input, err := os.ReadFile("../../input1.txt")
+  if err != nil {
+    b.Fatalf("unable to read input: %v", err)
+  }
+  for i := 0; i < b.N; i++ {
+    ProcessMulInstructions(string(input))
+  }
+}
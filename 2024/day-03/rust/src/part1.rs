@@ -8,8 +8,8 @@ use nom::sequence::separated_pair;
 use nom::IResult;
 use nom::Parser;
 
-pub fn process(input: &'static str) -> Result<String> {
-    let (_, instructions) = parse_instructions(input)?;
+pub fn process(input: &str) -> Result<String> {
+    let (_, instructions) = parse_instructions(input).map_err(|e| e.to_owned())?;
     Ok(instructions
         .iter()
         .map(|ins| match ins {
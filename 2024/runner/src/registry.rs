@@ -0,0 +1,10 @@
+use util::solution::Registry;
+
+/// Every solved day. `cargo xtask generate` appends new days here.
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+    registry.register::<day_01::Day>();
+    registry.register::<day_02::Day>();
+    registry.register::<day_03::Day>();
+    registry
+}
@@ -7,6 +7,10 @@ import (
 )
 
 func main() {
-	inputFileContents := util.GetInputContents("{{day}}/input1.txt")
-	fmt.Println(string(inputFileContents))
+	inputFileContents := util.GetInputContents("{{day_dash}}/input1.txt")
+	fmt.Println(Process(string(inputFileContents)))
+}
+
+func Process(input string) string {
+	return input
 }
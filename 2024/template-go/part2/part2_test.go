@@ -1,7 +1,20 @@
 package main
 
-import "testing"
+import (
+	"os"
+	"testing"
+)
 
 func TestPart2(t *testing.T) {
 	t.Errorf("noper")
 }
+
+func BenchmarkPart2(b *testing.B) {
+	input, err := os.ReadFile("../../input1.txt")
+	if err != nil {
+		b.Fatalf("unable to read input: %v", err)
+	}
+	for i := 0; i < b.N; i++ {
+		Process(string(input))
+	}
+}
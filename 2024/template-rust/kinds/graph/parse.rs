@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use nom::{
+    bytes::complete::tag,
+    character::complete::alphanumeric1,
+    multi::separated_list1,
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+/// Every `A = (B, C)` line as an edge list, lines in any other shape are skipped.
+pub type Parsed<'a> = BTreeMap<&'a str, Vec<&'a str>>;
+
+pub fn parse(input: &str) -> Result<Parsed<'_>> {
+    Ok(input
+        .lines()
+        .filter_map(|line| parse_node(line).ok())
+        .map(|(_, node)| node)
+        .collect())
+}
+
+fn parse_node(input: &str) -> IResult<&str, (&str, Vec<&str>)> {
+    separated_pair(
+        alphanumeric1,
+        tag(" = "),
+        delimited(
+            tag("("),
+            separated_list1(tag(", "), alphanumeric1),
+            tag(")"),
+        ),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let graph = parse("LR\n\nAAA = (BBB, CCC)\nBBB = (AAA, ZZZ)\n")?;
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.values().map(Vec::len).sum::<usize>(), 4);
+        assert_eq!(
+            graph,
+            BTreeMap::from([("AAA", vec!["BBB", "CCC"]), ("BBB", vec!["AAA", "ZZZ"])])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use anyhow::Result;
+use nom::{
+    character::complete::{i64, line_ending, space0, space1},
+    multi::separated_list1,
+    sequence::preceded,
+    IResult,
+};
+
+/// One row of numbers per line, separated by any amount of whitespace.
+pub type Parsed<'a> = Vec<Vec<i64>>;
+
+pub fn parse(input: &str) -> Result<Parsed<'_>> {
+    let (_, rows) = parse_rows(input).map_err(|e| e.to_owned())?;
+    Ok(rows)
+}
+
+fn parse_rows(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
+    separated_list1(line_ending, preceded(space0, separated_list1(space1, i64)))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let rows = parse("0 3   6\n -1 4\n")?;
+        assert_eq!(rows.iter().map(Vec::len).collect::<Vec<_>>(), vec![3, 2]);
+        assert_eq!(rows, vec![vec![0, 3, 6], vec![-1, 4]]);
+        Ok(())
+    }
+}
@@ -1,2 +1,26 @@
+use anyhow::Result;
+
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day;
+
+impl util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = {{day_num}};
+
+    type Parsed<'a> = parse::Parsed<'a>;
+
+    fn parse(input: &str) -> Result<parse::Parsed<'_>> {
+        parse::parse(input)
+    }
+
+    fn part1(parsed: &parse::Parsed<'_>) -> Result<String> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &parse::Parsed<'_>) -> Result<String> {
+        part2::process(parsed)
+    }
+}
@@ -0,0 +1,8 @@
+use anyhow::Result;
+
+/// What both parts work on, parsed once from the input.
+pub type Parsed<'a> = Vec<&'a str>;
+
+pub fn parse(input: &str) -> Result<Parsed<'_>> {
+    Ok(input.lines().collect())
+}
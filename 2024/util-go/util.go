@@ -2,11 +2,24 @@ package util
 
 import (
 	"fmt"
+	"io"
 	"log"
 	"os"
 )
 
+// GetInputContents reads the puzzle input from fileName. A path given as the
+// first program argument takes its place, and "-" reads stdin instead.
 func GetInputContents(fileName string) []byte {
+	if len(os.Args) > 1 {
+		fileName = os.Args[1]
+	}
+	if fileName == "-" {
+		inputFileContents, err := io.ReadAll(os.Stdin)
+		if err != nil {
+			log.Fatalf("unable to read stdin: %v", err)
+		}
+		return inputFileContents
+	}
 	inputFileContents, err := os.ReadFile(fileName)
 	if err != nil {
 		log.Fatalf("unable to read file: %v", err)
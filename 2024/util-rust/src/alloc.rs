@@ -0,0 +1,111 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// What was already live at the last [`reset`], left out of the peak.
+static BASELINE: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting every allocation on the way through.
+///
+/// Install it with `#[global_allocator] static ALLOC: Counting = Counting;`,
+/// then [`reset`] before the code to measure and read [`stats`] after it.
+pub struct Counting;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Calls to `alloc` and `realloc` since the last reset.
+    pub allocations: usize,
+    /// Bytes handed out since the last reset, freed or not.
+    pub bytes: usize,
+    /// The most bytes live at once since the last reset, on top of what was
+    /// already live at the reset.
+    pub peak_bytes: usize,
+}
+
+fn record(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            record(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting from zero, the peak from whatever is live right now.
+pub fn reset() {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    BASELINE.store(live, Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+}
+
+pub fn stats() -> AllocStats {
+    AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK
+            .load(Ordering::Relaxed)
+            .saturating_sub(BASELINE.load(Ordering::Relaxed)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: Counting = Counting;
+
+    #[test]
+    fn test_stats() {
+        // other tests allocate on their own threads too, so only lower bounds hold
+        reset();
+        let mut v = Vec::<u64>::with_capacity(1000);
+        v.extend(0..1000);
+        let big = vec![0u8; 1 << 20];
+        drop(big);
+        let small = vec![0u8; 16];
+
+        let stats = stats();
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 8000 + (1 << 20) + 16);
+        assert!(stats.peak_bytes >= 8000 + (1 << 20));
+        drop((v, small));
+    }
+}
@@ -0,0 +1,256 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states, each made from the one before by the same
+/// step, starts going round in a loop.
+///
+/// The detectors below step the state in place, so a simulation that
+/// updates a big grid does not have to build a new one every step. They
+/// never return for a sequence that does not repeat, but [`nth`] and
+/// [`hashed_nth`] stop looking once they have taken `n` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step whose state comes round again.
+    pub start: usize,
+    /// How many steps one lap of the loop takes.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`, at most
+    /// `start + period - 1`.
+    pub fn earliest(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+
+    /// The state after `n` steps from `initial`, taking no more than
+    /// [`Cycle::earliest`] steps to get there.
+    pub fn state_at<S: Clone>(&self, initial: &S, mut step: impl FnMut(&mut S), n: usize) -> S {
+        let mut state = initial.clone();
+        for _ in 0..self.earliest(n) {
+            step(&mut state);
+        }
+        state
+    }
+}
+
+/// The state after `n` steps from `initial`, however big `n` is, found
+/// with [`brent`]. Takes no more than `n` steps when nothing repeats by then.
+pub fn nth<S: Clone + Eq>(initial: &S, mut step: impl FnMut(&mut S), n: usize) -> S {
+    match brent_within(initial, &mut step, n) {
+        Ok(cycle) => cycle.state_at(initial, step, n),
+        Err(state) => state,
+    }
+}
+
+/// Floyd's tortoise and hare. Only needs `Eq` and keeps two states at a
+/// time, but steps through the loop about three times over.
+pub fn floyd<S: Clone + Eq>(initial: &S, mut step: impl FnMut(&mut S)) -> Cycle {
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    step(&mut tortoise);
+    step(&mut hare);
+    step(&mut hare);
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        step(&mut hare);
+    }
+
+    // the hare is now a whole number of laps ahead, so setting off together
+    // from the beginning and from there, the two first meet where the loop starts
+    let mut tortoise = initial.clone();
+    let mut start = 0;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    step(&mut hare);
+    while tortoise != hare {
+        step(&mut hare);
+        period += 1;
+    }
+    Cycle { start, period }
+}
+
+/// Brent's variant of [`floyd`], just as frugal but with fewer steps: the
+/// hare runs laps that double in length and the tortoise waits at the
+/// start of each one, so the lap the hare catches it in is the period.
+pub fn brent<S: Clone + Eq>(initial: &S, step: impl FnMut(&mut S)) -> Cycle {
+    match brent_within(initial, step, usize::MAX) {
+        Ok(cycle) => cycle,
+        Err(_) => unreachable!("no loop runs for usize::MAX steps"),
+    }
+}
+
+/// [`brent`], giving up with the state after `limit` steps when no state
+/// has come round by then.
+fn brent_within<S: Clone + Eq>(
+    initial: &S,
+    mut step: impl FnMut(&mut S),
+    limit: usize,
+) -> Result<Cycle, S> {
+    let mut lap = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    if limit == 0 {
+        return Err(hare);
+    }
+    step(&mut hare);
+    let mut steps = 1;
+    while tortoise != hare {
+        if steps == limit {
+            return Err(hare);
+        }
+        if period == lap {
+            tortoise = hare.clone();
+            lap *= 2;
+            period = 0;
+        }
+        step(&mut hare);
+        steps += 1;
+        period += 1;
+    }
+
+    // with the hare a period ahead, the two first meet where the loop starts
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        step(&mut hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+    Ok(Cycle { start, period })
+}
+
+/// Remembers every state it sees, so it steps to the first repeat and no
+/// further, at the cost of hashing and keeping all of them.
+pub fn hashed<S: Clone + Hash + Eq>(initial: &S, step: impl FnMut(&mut S)) -> Cycle {
+    hashed_within(initial, step, usize::MAX)
+        .0
+        .expect("no loop runs for usize::MAX steps")
+}
+
+/// Like [`nth`], but picks the state out of the ones [`hashed`] saw rather
+/// than stepping to it again.
+pub fn hashed_nth<S: Clone + Hash + Eq>(initial: &S, step: impl FnMut(&mut S), n: usize) -> S {
+    let (cycle, mut states) = hashed_within(initial, step, n);
+    let earliest = cycle.map_or(n, |cycle| cycle.earliest(n));
+    states.swap_remove(earliest)
+}
+
+/// The cycle, or `None` when no state has come round again within `limit`
+/// steps, and every state up to there, indexed by the step it was seen at.
+fn hashed_within<S: Clone + Hash + Eq>(
+    initial: &S,
+    mut step: impl FnMut(&mut S),
+    limit: usize,
+) -> (Option<Cycle>, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial.clone();
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                period: states.len() - start,
+            };
+            return (Some(cycle), states);
+        }
+        seen.insert(state.clone(), states.len());
+        if states.len() == limit {
+            states.push(state);
+            return (None, states);
+        }
+        states.push(state.clone());
+        step(&mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn cycle(start: usize, period: usize) -> Cycle {
+        Cycle { start, period }
+    }
+
+    /// Finds the cycle the slow way, by looking back over every state.
+    fn brute_force(initial: u32, step: impl Fn(&mut u32)) -> Cycle {
+        let mut states = vec![initial];
+        loop {
+            let mut next = *states.last().unwrap();
+            step(&mut next);
+            if let Some(start) = states.iter().position(|&s| s == next) {
+                return cycle(start, states.len() - start);
+            }
+            states.push(next);
+        }
+    }
+
+    #[test]
+    fn test_detectors() {
+        for modulus in 1..60 {
+            for initial in 0..modulus {
+                let step = |x: &mut u32| *x = (*x * *x + 3) % modulus;
+                let expected = brute_force(initial, step);
+                assert_eq!(floyd(&initial, step), expected, "{initial} mod {modulus}");
+                assert_eq!(brent(&initial, step), expected, "{initial} mod {modulus}");
+                assert_eq!(hashed(&initial, step), expected, "{initial} mod {modulus}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_shapes() {
+        // a loop from the very first state
+        let step = |x: &mut u32| *x = (*x + 1) % 5;
+        assert_eq!(brent(&0, step), cycle(0, 5));
+        // a tail into a fixed point
+        let step = |x: &mut u32| *x = x.saturating_sub(1);
+        assert_eq!(floyd(&4, step), cycle(4, 1));
+        assert_eq!(hashed(&4, step), cycle(4, 1));
+    }
+
+    #[test]
+    fn test_state_at() {
+        // 10, 11, ..., 19 then round 15..=19
+        let step = |x: &mut u32| *x = if *x == 19 { 15 } else { *x + 1 };
+        let found = brent(&10, step);
+        assert_eq!(found, cycle(5, 5));
+        assert_eq!(found.earliest(3), 3);
+        assert_eq!(found.earliest(12), 7);
+        assert_eq!(found.earliest(1_000_000_000), 5);
+        for n in 0..40 {
+            let mut state = 10;
+            (0..n).for_each(|_| step(&mut state));
+            assert_eq!(found.state_at(&10, step, n), state);
+            assert_eq!(nth(&10, step, n), state);
+            assert_eq!(hashed_nth(&10, step, n), state);
+        }
+    }
+
+    #[test]
+    fn test_nth_without_repeat() {
+        // counting up never repeats, so these only return by stopping at `n`
+        let step = |x: &mut u64| *x += 1;
+        for n in 0..10 {
+            assert_eq!(nth(&0, step, n), n as u64);
+            assert_eq!(hashed_nth(&0, step, n), n as u64);
+        }
+        assert_eq!(nth(&0, step, 100_000), 100_000);
+        assert_eq!(hashed_nth(&0, step, 100_000), 100_000);
+    }
+}
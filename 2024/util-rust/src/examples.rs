@@ -0,0 +1,109 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Expected answers of a day's examples, written by `cargo xtask example add`.
+pub const EXPECTED_FILE: &str = "expected.toml";
+
+/// Runs every stored example that has an expected answer for `part` through
+/// `process`, failing with every example that answered something else.
+///
+/// Examples live in `examples/` next to the day crate at `manifest_dir`, or
+/// one folder up when the crate sits inside the day folder.
+pub fn check<T, E>(
+    manifest_dir: &str,
+    part: u8,
+    process: impl Fn(&str) -> Result<T, E>,
+) -> Result<()>
+where
+    T: ToString,
+    E: Display,
+{
+    let examples_dir = examples_dir(Path::new(manifest_dir))?;
+    let path = examples_dir.join(EXPECTED_FILE);
+    let expected = fs::read_to_string(&path)
+        .with_context(|| format!("read {}", path.display()))?
+        .parse::<toml::Table>()
+        .with_context(|| format!("parse {}", path.display()))?;
+
+    let part_key = format!("part{part}");
+    let mut checked = 0;
+    let mut failures = vec![];
+    for (example, answers) in &expected {
+        let Some(answer) = answers.get(&part_key).and_then(|a| a.as_str()) else {
+            continue;
+        };
+        let input = fs::read_to_string(examples_dir.join(format!("{example}.txt")))
+            .with_context(|| format!("read example {example}"))?;
+        checked += 1;
+        match process(&input) {
+            Ok(actual) if actual.to_string() == answer => {}
+            Ok(actual) => failures.push(format!(
+                "example {example}: expected {answer}, got {}",
+                actual.to_string()
+            )),
+            Err(e) => failures.push(format!("example {example}: expected {answer}, failed: {e}")),
+        }
+    }
+
+    if checked == 0 {
+        bail!("no examples expect an answer for {part_key} yet, add one with `cargo xtask example add`");
+    }
+    if !failures.is_empty() {
+        bail!("{}", failures.join("\n"));
+    }
+    Ok(())
+}
+
+fn examples_dir(manifest_dir: &Path) -> Result<PathBuf> {
+    manifest_dir
+        .ancestors()
+        .take(2)
+        .map(|dir| dir.join("examples"))
+        .find(|dir| dir.join(EXPECTED_FILE).is_file())
+        .with_context(|| {
+            format!(
+                "no examples/{EXPECTED_FILE} for {}, add an example with `cargo xtask example add`",
+                manifest_dir.display()
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// A day folder with two examples, removed again when it is dropped.
+    fn day_dir() -> TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let examples = dir.path().join("examples");
+        fs::create_dir_all(&examples).unwrap();
+        fs::write(examples.join("01.txt"), "1\n2\n").unwrap();
+        fs::write(examples.join("02.txt"), "3\n").unwrap();
+        fs::write(
+            examples.join(EXPECTED_FILE),
+            "[01]\npart1 = \"3\"\n\n[02]\npart1 = \"3\"\npart2 = \"9\"\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    fn sum(input: &str) -> Result<u32> {
+        Ok(input.lines().map(|l| l.parse::<u32>().unwrap()).sum())
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = day_dir();
+        let manifest_dir = dir.path().join("rust");
+
+        assert!(check(manifest_dir.to_str().unwrap(), 1, sum).is_ok());
+        let err = check(manifest_dir.to_str().unwrap(), 2, sum).unwrap_err();
+        assert_eq!(err.to_string(), "example 02: expected 9, got 3");
+        assert!(check(dir.path().to_str().unwrap(), 3, sum).is_err());
+    }
+}
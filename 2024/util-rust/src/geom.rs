@@ -0,0 +1,302 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The signed integers a [`Pos`] can be made of.
+pub trait Coord:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn from_usize(n: usize) -> Option<Self>;
+    fn to_usize(self) -> Option<usize>;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn from_usize(n: usize) -> Option<Self> {
+                    Self::try_from(n).ok()
+                }
+
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize);
+
+/// A point on a grid, or the step between two of them.
+///
+/// `x` grows to the right and `y` grows down, the way input maps are read,
+/// so [`Dir::N`] is `y - 1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Pos<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Steps along the grid lines only, `|dx| + |dy|`.
+    pub fn manhattan(self, other: Self) -> T {
+        let d = self - other;
+        d.x.abs() + d.y.abs()
+    }
+
+    /// Steps when diagonals count as one too, `max(|dx|, |dy|)`.
+    pub fn chebyshev(self, other: Self) -> T {
+        let d = self - other;
+        d.x.abs().max(d.y.abs())
+    }
+
+    /// The position one step towards `dir`.
+    pub fn step(self, dir: Dir) -> Self {
+        self + dir.offset()
+    }
+
+    /// The four orthogonal neighbours, in the order of [`Dir::ALL`].
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Dir::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// All eight neighbours, clockwise from north like [`Dir::ALL`], each
+    /// diagonal after the direction it turns right from.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Dir::ALL.into_iter().flat_map(move |dir| {
+            let side = self.step(dir);
+            [side, side.step(dir.turn_right())]
+        })
+    }
+
+    /// Where this position is in a grid stored row after row, `None` when it
+    /// is left of, above or right of a grid `width` wide.
+    pub fn to_index(self, width: usize) -> Option<usize> {
+        let x = self.x.to_usize().filter(|&x| x < width)?;
+        let y = self.y.to_usize()?;
+        y.checked_mul(width)?.checked_add(x)
+    }
+
+    /// The position of `index` in a grid stored row after row, `width` wide.
+    pub fn from_index(index: usize, width: usize) -> Option<Self> {
+        if width == 0 {
+            return None;
+        }
+        Some(Self::new(
+            T::from_usize(index % width)?,
+            T::from_usize(index / width)?,
+        ))
+    }
+}
+
+impl<T> From<(T, T)> for Pos<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Pos<T>> for (T, T) {
+    fn from(pos: Pos<T>) -> Self {
+        (pos.x, pos.y)
+    }
+}
+
+impl<T: Coord> Add for Pos<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> Sub for Pos<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Pos<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord> SubAssign for Pos<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coord> Neg for Pos<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Scales a step, `Dir::E.offset() * 3` is three to the right.
+impl<T: Coord> Mul<T> for Pos<T> {
+    type Output = Self;
+
+    fn mul(self, by: T) -> Self {
+        Self::new(self.x * by, self.y * by)
+    }
+}
+
+/// A compass direction on a map with north at the top.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir {
+    /// Clockwise from north.
+    pub const ALL: [Dir; 4] = [Dir::N, Dir::E, Dir::S, Dir::W];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir::N => Dir::E,
+            Dir::E => Dir::S,
+            Dir::S => Dir::W,
+            Dir::W => Dir::N,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Dir::N => Dir::W,
+            Dir::W => Dir::S,
+            Dir::S => Dir::E,
+            Dir::E => Dir::N,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Dir::N => Dir::S,
+            Dir::E => Dir::W,
+            Dir::S => Dir::N,
+            Dir::W => Dir::E,
+        }
+    }
+
+    /// The step one move in this direction makes.
+    pub fn offset<T: Coord>(self) -> Pos<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        match self {
+            Dir::N => Pos::new(zero, -one),
+            Dir::E => Pos::new(one, zero),
+            Dir::S => Pos::new(zero, one),
+            Dir::W => Pos::new(-one, zero),
+        }
+    }
+
+    /// The direction a single orthogonal step goes in, `None` for anything else.
+    pub fn from_offset<T: Coord>(offset: Pos<T>) -> Option<Self> {
+        Dir::ALL.into_iter().find(|dir| dir.offset() == offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Pos::new(3, -2);
+        let b = Pos::new(-1, 4);
+        assert_eq!(a + b, Pos::new(2, 2));
+        assert_eq!(a - b, Pos::new(4, -6));
+        assert_eq!(-a, Pos::new(-3, 2));
+        assert_eq!(Dir::E.offset() * 3, Pos::new(3, 0));
+
+        let mut c = a;
+        c += b;
+        c -= Pos::new(2, 2);
+        assert_eq!(c, Pos::new(0, 0));
+        assert_eq!(Pos::from((5_i64, 6)), Pos::new(5, 6));
+        assert_eq!(<(i32, i32)>::from(Pos::new(1, 2)), (1, 2));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Pos::<i64>::new(1, 1);
+        let b = Pos::new(4, -3);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
+    #[test]
+    fn test_dir() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.offset::<i32>() + dir.reverse().offset(), Pos::new(0, 0));
+            assert_eq!(Dir::from_offset(dir.offset::<i8>()), Some(dir));
+        }
+        assert_eq!(Pos::new(2, 2).step(Dir::N), Pos::new(2, 1));
+        assert_eq!(Dir::from_offset(Pos::new(1, 1)), None);
+        assert_eq!(
+            Pos::new(0, 0).neighbours4().collect::<Vec<_>>(),
+            vec![
+                Pos::new(0, -1),
+                Pos::new(1, 0),
+                Pos::new(0, 1),
+                Pos::new(-1, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let around = Pos::new(0, 0).neighbours8().collect::<Vec<_>>();
+        assert_eq!(
+            around,
+            [
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1)
+            ]
+            .map(Pos::from)
+        );
+        assert!(around.iter().all(|n| n.chebyshev(Pos::new(0, 0)) == 1));
+    }
+
+    #[test]
+    fn test_index() {
+        assert_eq!(Pos::new(2, 1).to_index(3), Some(5));
+        assert_eq!(Pos::new(3, 1).to_index(3), None);
+        assert_eq!(Pos::new(-1, 1).to_index(3), None);
+        assert_eq!(Pos::<isize>::from_index(5, 3), Some(Pos::new(2, 1)));
+        assert_eq!(Pos::<i8>::from_index(1000, 1), None);
+        assert_eq!(Pos::<isize>::from_index(1, 0), None);
+    }
+}
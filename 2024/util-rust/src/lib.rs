@@ -1,5 +1,16 @@
 use std::fmt::Display;
 
+pub mod alloc;
+pub mod cycle;
+pub mod examples;
+pub mod geom;
+pub mod grid;
+pub mod math;
+pub mod solution;
+
+pub use grid::Grid;
+pub use solution::Solution;
+
 pub fn print_matrix<T>(matrix: &[Vec<T>])
 where
     T: Display + Clone,
@@ -0,0 +1,157 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use clap::Args;
+
+use crate::{
+    input::InputArgs,
+    limits::LimitArgs,
+    solve::timed_output,
+    table::print_table,
+    util::{get_day_string, get_part_string},
+    year::Year,
+};
+
+/// Where the counting runner is built, so it does not evict the normal one.
+const ALLOC_TARGET_DIR: &str = "target/alloc";
+
+#[derive(Args)]
+pub struct AllocArgs {
+    day: u8,
+    part: u8,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// What the runner reports on stderr when built with its `alloc` feature.
+#[derive(Debug, Default, PartialEq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak_bytes: u64,
+}
+
+pub fn alloc(year: &Year, args: &AllocArgs) -> Result<()> {
+    let input = args
+        .input
+        .resolve(&year.day_dir(args.day), &year.input_name(args.part))?;
+    let runner = build_alloc_runner(&year.root)?;
+    let output = timed_output(
+        Command::new(runner)
+            .args(["run", &args.day.to_string(), &args.part.to_string()])
+            .args(["--input", &input.as_arg()])
+            .stdin(Stdio::inherit()),
+        &LimitArgs::default(),
+    )?;
+    if !output.success {
+        bail!(
+            "{} {} failed\n{}",
+            get_day_string(args.day),
+            get_part_string(args.part),
+            output.stderr.trim_end()
+        );
+    }
+    let stats = parse_stats(&output.stderr)?;
+
+    print_table(
+        &[
+            "day",
+            "part",
+            "answer",
+            "allocations",
+            "allocated",
+            "peak live",
+        ],
+        &[vec![
+            get_day_string(args.day),
+            get_part_string(args.part),
+            output.answer.unwrap_or_default(),
+            stats.allocations.to_string(),
+            format_bytes(stats.bytes),
+            format_bytes(stats.peak_bytes),
+        ]],
+    );
+    Ok(())
+}
+
+/// Builds the runner with its counting global allocator turned on.
+fn build_alloc_runner(project_root: &Path) -> Result<PathBuf> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .current_dir(project_root)
+        .args(["build", "--release", "--package", "runner"])
+        .args(["--features", "alloc", "--target-dir", ALLOC_TARGET_DIR])
+        .status()?;
+    if !status.success() {
+        bail!("building the runner with allocation counting failed");
+    }
+    Ok(project_root
+        .join(ALLOC_TARGET_DIR)
+        .join("release")
+        .join(format!("runner{}", env::consts::EXE_SUFFIX)))
+}
+
+fn parse_stats(stderr: &str) -> Result<AllocStats> {
+    let mut stats = AllocStats::default();
+    let mut found = 0;
+    for line in stderr.lines() {
+        let Some((name, value)) = line.split_once(": ") else {
+            continue;
+        };
+        let field = match name {
+            "allocations" => &mut stats.allocations,
+            "allocated bytes" => &mut stats.bytes,
+            "peak bytes" => &mut stats.peak_bytes,
+            _ => continue,
+        };
+        *field = value
+            .trim()
+            .parse()
+            .with_context(|| format!("parse {name} from the runner"))?;
+        found += 1;
+    }
+    if found != 3 {
+        bail!("the runner did not report its allocations, was it built with `--features alloc`?");
+    }
+    Ok(stats)
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes as f64 {
+        b if b >= 1024.0 * 1024.0 * 1024.0 => format!("{:.1} GiB", b / (1024.0 * 1024.0 * 1024.0)),
+        b if b >= 1024.0 * 1024.0 => format!("{:.1} MiB", b / (1024.0 * 1024.0)),
+        b if b >= 1024.0 => format!("{:.1} KiB", b / 1024.0),
+        _ => format!("{bytes} B"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_stats() {
+        let stderr = "allocations: 120\nallocated bytes: 5242880\npeak bytes: 2048\n";
+        assert_eq!(
+            parse_stats(stderr).unwrap(),
+            AllocStats {
+                allocations: 120,
+                bytes: 5_242_880,
+                peak_bytes: 2048,
+            }
+        );
+        assert!(parse_stats("elapsed: 1ms\n").is_err());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KiB");
+        assert_eq!(format_bytes(5_242_880), "5.0 MiB");
+    }
+}
@@ -0,0 +1,49 @@
+use anyhow::{Context, Result};
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use toml_edit::{value, DocumentMut, Item, Table};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-correct answers, keyed by `day-XX` then `partN`.
+#[derive(Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Answers {
+    pub fn load(project_root: &Path) -> Result<Self> {
+        let path = project_root.join(ANSWERS_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(&path)?;
+        toml::from_str(&contents).with_context(|| format!("parse {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days
+            .get(&format!("day-{day:02}"))
+            .and_then(|parts| parts.get(&format!("part{part}")))
+            .map(String::as_str)
+    }
+
+    /// Writes a newly confirmed answer into the ledger, keeping the rest of the file as is.
+    pub fn record(project_root: &Path, day: u8, part: u8, answer: &str) -> Result<()> {
+        let path = project_root.join(ANSWERS_FILE);
+        let contents = if path.exists() {
+            fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+        let mut doc = contents
+            .parse::<DocumentMut>()
+            .with_context(|| format!("parse {}", path.display()))?;
+        doc.entry(&format!("day-{day:02}"))
+            .or_insert(Item::Table(Table::new()))[&format!("part{part}")] = value(answer);
+        fs::write(&path, doc.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use clap::Args;
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Advent of Code asks automated tools to say who they are.
+pub const USER_AGENT: &str = concat!(
+    "github.com/pitoniak32/advent_of_code xtask/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// The least time to leave between two requests, across xtask runs.
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Args)]
+pub struct AocArgs {
+    /// advent of code session cookie, defaults to `session` in the config file
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// where to download from, defaults to `base_url` in the config file or adventofcode.com
+    #[arg(long, env = "AOC_BASE_URL")]
+    base_url: Option<String>,
+}
+
+/// `~/.config/aoc-xtask/config.toml`, or the file `AOC_CONFIG` points at.
+#[derive(Default, Deserialize)]
+pub struct Config {
+    session: Option<String>,
+    base_url: Option<String>,
+    min_interval_secs: Option<u64>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self> {
+        let path = match env::var_os("AOC_CONFIG") {
+            Some(path) => PathBuf::from(path),
+            None => match config_dir() {
+                Some(dir) => dir.join("config.toml"),
+                None => return Ok(Self::default()),
+            },
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        toml::from_str(&fs::read_to_string(&path)?)
+            .with_context(|| format!("parse {}", path.display()))
+    }
+}
+
+fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|dir| dir.join("aoc-xtask"))
+}
+
+fn cache_dir() -> PathBuf {
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(env::temp_dir)
+        .join("aoc-xtask")
+}
+
+/// Keeps requests at least `min_interval` apart by remembering when the last
+/// one went out in `stamp`, so separate xtask runs share the limit.
+pub struct Throttle {
+    stamp: PathBuf,
+    min_interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(stamp: PathBuf, min_interval: Duration) -> Self {
+        Self {
+            stamp,
+            min_interval,
+        }
+    }
+
+    fn wait(&self) -> Result<()> {
+        let now = unix_millis();
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|stamp| stamp.trim().parse::<u64>().ok());
+        if let Some(last) = last {
+            let next = last + self.min_interval.as_millis() as u64;
+            if next > now {
+                // both stamps are cut to whole milliseconds, the extra one covers that
+                let wait = Duration::from_millis(next - now + 1);
+                eprintln!("waiting {wait:?} before the next request to advent of code");
+                thread::sleep(wait);
+            }
+        }
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.stamp, unix_millis().to_string())?;
+        Ok(())
+    }
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, throttle: Throttle) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            throttle,
+        }
+    }
+
+    /// Builds a client from the command line, falling back to the config file.
+    pub fn from_args(args: &AocArgs) -> Result<Self> {
+        let config = Config::load()?;
+        let Some(session) = args.session.as_ref().or(config.session.as_ref()) else {
+            bail!("no advent of code session, set AOC_SESSION or `session` in the config file");
+        };
+        let base_url = args
+            .base_url
+            .as_deref()
+            .or(config.base_url.as_deref())
+            .unwrap_or(DEFAULT_BASE_URL);
+        let min_interval = config
+            .min_interval_secs
+            .map_or(DEFAULT_MIN_INTERVAL, Duration::from_secs);
+        Ok(Self::new(
+            base_url,
+            session,
+            Throttle::new(cache_dir().join("last-request"), min_interval),
+        ))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// The puzzle page, which only includes part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        self.throttle.wait()?;
+        let url = format!("{}{path}", self.base_url);
+        match self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+        {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(404, _)) => {
+                bail!("{url} is not there yet, is the day unlocked?")
+            }
+            Err(ureq::Error::Status(code @ (400 | 401 | 500), _)) => {
+                bail!("{url} answered {code}, the session is probably expired")
+            }
+            Err(e) => Err(e).with_context(|| format!("download {url}")),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// A local HTTP stand-in for advent of code that answers every request
+    /// from `routes` and remembers the requests it got.
+    pub struct MockServer {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl MockServer {
+        pub fn start(routes: Vec<(&'static str, u16, String)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+            let seen = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let stream = stream.unwrap();
+                    let mut request = String::new();
+                    let mut reader = BufReader::new(&stream);
+                    loop {
+                        let mut line = String::new();
+                        if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                            break;
+                        }
+                        request.push_str(&line);
+                    }
+                    let path = request.split_whitespace().nth(1).unwrap_or_default();
+                    let (status, body) = routes
+                        .iter()
+                        .find(|(route, _, _)| *route == path)
+                        .map_or((404, String::new()), |(_, status, body)| {
+                            (*status, body.clone())
+                        });
+                    seen.lock().unwrap().push(request);
+                    let mut writer = &stream;
+                    write!(
+                        writer,
+                        "HTTP/1.1 {status} MOCK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+            Self { url, requests }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mock::MockServer;
+    use pretty_assertions::assert_eq;
+    use std::time::Instant;
+
+    #[test]
+    fn test_input_request() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start(vec![("/2023/day/1/input", 200, "1abc2\n".to_string())]);
+        let client = Client::new(
+            &server.url,
+            "abc123",
+            Throttle::new(dir.path().join("last-request"), Duration::ZERO),
+        );
+
+        assert_eq!(client.input(2023, 1).unwrap(), "1abc2\n");
+        assert!(client.input(2023, 2).is_err());
+
+        let requests = server.requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /2023/day/1/input "));
+        assert!(requests[0].contains("Cookie: session=abc123"));
+        assert!(requests[0].contains(&format!("User-Agent: {USER_AGENT}")));
+    }
+
+    #[test]
+    fn test_throttle() {
+        let dir = tempfile::tempdir().unwrap();
+        let throttle = Throttle::new(dir.path().join("last-request"), Duration::from_millis(200));
+        let start = Instant::now();
+        throttle.wait().unwrap();
+        throttle.wait().unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+}
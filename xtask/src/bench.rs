@@ -0,0 +1,404 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use clap::{Args, Subcommand};
+use serde::{Deserialize, Serialize};
+
+use crate::table::print_table;
+
+pub const BENCH_FILE: &str = "benches.jsonl";
+
+/// The `part` the `parse` bench, timing the parsing both parts share, is recorded under.
+pub const PARSE_PART: u8 = 0;
+
+#[derive(Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct BenchArgs {
+    #[command(subcommand)]
+    pub command: Option<BenchCommands>,
+    #[arg(required = true)]
+    pub day: Option<u8>,
+    #[arg(required = true)]
+    pub part: Option<u8>,
+    /// also save this run as a named baseline to compare against later
+    #[arg(long)]
+    pub save_baseline: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum BenchCommands {
+    /// compare the latest bench of a day against the run before it or a named baseline
+    Compare(CompareArgs),
+}
+
+#[derive(Args)]
+pub struct CompareArgs {
+    day: u8,
+    part: Option<u8>,
+    /// compare against this saved baseline instead of the previous run
+    #[arg(short, long)]
+    baseline: Option<String>,
+    /// how many percent slower the median has to be to count as a regression
+    #[arg(short, long, default_value_t = 5.0)]
+    threshold: f64,
+}
+
+/// One benchmarked part, or the parse with [`PARSE_PART`], with every
+/// duration in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BenchRecord {
+    pub lang: String,
+    pub day: u8,
+    pub part: u8,
+    pub fastest_ns: f64,
+    pub slowest_ns: f64,
+    pub median_ns: f64,
+    pub mean_ns: f64,
+    pub samples: u64,
+    pub commit: Option<String>,
+    pub timestamp: u64,
+    pub baseline: Option<String>,
+}
+
+/// Timings for one benchmark as a bench tool reports them, before they are tied to a run.
+#[derive(Debug, PartialEq)]
+pub struct BenchRow {
+    pub name: String,
+    pub fastest_ns: f64,
+    pub slowest_ns: f64,
+    pub median_ns: f64,
+    pub mean_ns: f64,
+    pub samples: u64,
+}
+
+/// Pulls the timing rows out of divan's table output, skipping headers and
+/// any allocation counter rows.
+pub fn parse_divan(output: &str) -> Vec<BenchRow> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_start_matches(|c: char| c == '│' || c.is_whitespace());
+            let rest = line
+                .strip_prefix("├─")
+                .or_else(|| line.strip_prefix("╰─"))?;
+            let cells = rest.split('│').map(str::trim).collect::<Vec<_>>();
+            let [first, slowest, median, mean, samples, ..] = cells.as_slice() else {
+                return None;
+            };
+            let (name, fastest) = first.split_once(char::is_whitespace)?;
+            Some(BenchRow {
+                name: name.to_string(),
+                fastest_ns: parse_duration_ns(fastest.trim())?,
+                slowest_ns: parse_duration_ns(slowest)?,
+                median_ns: parse_duration_ns(median)?,
+                mean_ns: parse_duration_ns(mean)?,
+                samples: samples.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Parses durations like `71.56 µs` into nanoseconds.
+pub fn parse_duration_ns(duration: &str) -> Option<f64> {
+    let (value, unit) = duration.split_once(' ')?;
+    let scale = match unit.trim() {
+        "ps" => 0.001,
+        "ns" => 1.0,
+        "µs" | "μs" | "us" => 1_000.0,
+        "ms" => 1_000_000.0,
+        "s" => 1_000_000_000.0,
+        _ => return None,
+    };
+    value.parse::<f64>().ok().map(|v| v * scale)
+}
+
+pub fn format_ns(ns: f64) -> String {
+    match ns {
+        ns if ns >= 1_000_000_000.0 => format!("{:.3} s", ns / 1_000_000_000.0),
+        ns if ns >= 1_000_000.0 => format!("{:.3} ms", ns / 1_000_000.0),
+        ns if ns >= 1_000.0 => format!("{:.3} µs", ns / 1_000.0),
+        ns => format!("{ns:.1} ns"),
+    }
+}
+
+impl BenchRecord {
+    pub fn new(lang: &str, day: u8, part: u8, row: &BenchRow, run: &RunInfo) -> Self {
+        Self {
+            lang: lang.to_string(),
+            day,
+            part,
+            fastest_ns: row.fastest_ns,
+            slowest_ns: row.slowest_ns,
+            median_ns: row.median_ns,
+            mean_ns: row.mean_ns,
+            samples: row.samples,
+            commit: run.commit.clone(),
+            timestamp: run.timestamp,
+            baseline: run.baseline.clone(),
+        }
+    }
+
+    /// `parse` or `partN`, the name of the bench it was recorded from.
+    pub fn bench_name(&self) -> String {
+        bench_name(self.part)
+    }
+}
+
+fn bench_name(part: u8) -> String {
+    if part == PARSE_PART {
+        "parse".to_string()
+    } else {
+        format!("part{part}")
+    }
+}
+
+/// What every record from one bench invocation shares.
+pub struct RunInfo {
+    pub commit: Option<String>,
+    pub timestamp: u64,
+    pub baseline: Option<String>,
+}
+
+impl RunInfo {
+    pub fn now(project_root: &Path, baseline: Option<String>) -> Self {
+        Self {
+            commit: git_commit(project_root),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            baseline,
+        }
+    }
+}
+
+/// The short hash of `HEAD`, marked `-dirty` when the tree has uncommitted changes.
+fn git_commit(project_root: &Path) -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(project_root)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    let dirty = git(&["status", "--porcelain", "--", "."]).is_some_and(|s| !s.is_empty());
+    Some(if dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+pub fn append_records(project_root: &Path, records: &[BenchRecord]) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(project_root.join(BENCH_FILE))?;
+    for record in records {
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+    }
+    Ok(())
+}
+
+pub fn load_records(project_root: &Path) -> Result<Vec<BenchRecord>> {
+    let path = project_root.join(BENCH_FILE);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    fs::read_to_string(&path)?
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("parse {} line {}", path.display(), idx + 1))
+        })
+        .collect()
+}
+
+/// Whether a day's `Solution::Parsed` is the input itself, so its `parse`
+/// bench has nothing to time and is left out of the records.
+pub fn parses_nothing(crate_dir: &Path) -> bool {
+    ["lib.rs", "parse.rs"].iter().any(|file| {
+        fs::read_to_string(crate_dir.join("src").join(file))
+            .is_ok_and(|source| source.contains("Parsed<'a> = &'a str;"))
+    })
+}
+
+/// Turns the bench rows of one day into records and appends them to the history.
+/// Rows are matched to parts by their `partN` name, and `parse` to [`PARSE_PART`].
+pub fn record_rows(
+    project_root: &Path,
+    lang: &str,
+    day: u8,
+    rows: &[BenchRow],
+    baseline: Option<String>,
+) -> Result<Vec<BenchRecord>> {
+    let run = RunInfo::now(project_root, baseline);
+    let records = rows
+        .iter()
+        .filter_map(|row| {
+            let part = match row.name.as_str() {
+                "parse" => PARSE_PART,
+                name => name.strip_prefix("part")?.parse().ok()?,
+            };
+            Some(BenchRecord::new(lang, day, part, row, &run))
+        })
+        .collect::<Vec<_>>();
+    if records.is_empty() {
+        bail!("no benchmark results found in the bench output");
+    }
+    append_records(project_root, &records)?;
+    Ok(records)
+}
+
+/// Compares the latest run of each part and of the parse against the run
+/// before it, or the latest run saved under `--baseline`, and fails when any
+/// of them regressed.
+pub fn compare(project_root: &Path, lang: &str, args: &CompareArgs) -> Result<()> {
+    let records = load_records(project_root)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![PARSE_PART, 1, 2],
+    };
+
+    let mut rows = vec![];
+    let mut regressions = 0;
+    for part in parts {
+        let history = records
+            .iter()
+            .filter(|r| r.lang == lang && r.day == args.day && r.part == part)
+            .collect::<Vec<_>>();
+        let Some((current, earlier)) = history.split_last() else {
+            continue;
+        };
+        let base = match &args.baseline {
+            Some(name) => history
+                .iter()
+                .rev()
+                .find(|r| r.baseline.as_ref() == Some(name)),
+            None => earlier.last(),
+        };
+        let Some(base) = base else {
+            rows.push(vec![
+                bench_name(part),
+                String::new(),
+                format_ns(current.median_ns),
+                String::new(),
+                "no baseline".to_string(),
+            ]);
+            continue;
+        };
+
+        let change = (current.median_ns - base.median_ns) / base.median_ns * 100.0;
+        let status = if change > args.threshold {
+            regressions += 1;
+            "regression"
+        } else if change < -args.threshold {
+            "improvement"
+        } else {
+            "same"
+        };
+        rows.push(vec![
+            bench_name(part),
+            format_ns(base.median_ns),
+            format_ns(current.median_ns),
+            format!("{change:+.1}%"),
+            status.to_string(),
+        ]);
+    }
+
+    if rows.is_empty() {
+        bail!("no bench results recorded for day {} yet", args.day);
+    }
+    print_table(
+        &["part", "base median", "median", "change", "status"],
+        &rows,
+    );
+
+    if regressions > 0 {
+        bail!(
+            "{regressions} part(s) regressed by more than {}%",
+            args.threshold
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_divan() {
+        let output = "day_01    fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ part1  71.56 µs      │ 90.86 µs      │ 73.22 µs      │ 74.1 µs       │ 100     │ 100
+╰─ part2  1.382 ms      │ 2.86 ms       │ 1.473 ms      │ 1.516 ms      │ 100     │ 100
+          alloc:        │               │               │               │         │
+            18          │ 18            │ 18            │ 18            │         │
+            1.2 KB      │ 1.2 KB        │ 1.2 KB        │ 1.2 KB        │         │
+";
+        let rows = parse_divan(output);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].name, "part1");
+        assert_eq!(rows[0].median_ns, 73_220.0);
+        assert_eq!(rows[0].samples, 100);
+        assert_eq!(rows[1].name, "part2");
+        assert_eq!(rows[1].slowest_ns, 2_860_000.0);
+    }
+
+    #[test]
+    fn test_record_rows() {
+        let dir = tempfile::tempdir().unwrap();
+        let row = |name: &str| BenchRow {
+            name: name.to_string(),
+            fastest_ns: 1.0,
+            slowest_ns: 3.0,
+            median_ns: 2.0,
+            mean_ns: 2.0,
+            samples: 100,
+        };
+        let rows = [row("parse"), row("part2"), row("setup")];
+
+        let records = record_rows(dir.path(), "rust", 5, &rows, None).unwrap();
+        let names = records
+            .iter()
+            .map(BenchRecord::bench_name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["parse", "part2"]);
+        assert_eq!(records[0].part, PARSE_PART);
+        assert_eq!(load_records(dir.path()).unwrap(), records);
+    }
+
+    #[test]
+    fn test_parses_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir(&src).unwrap();
+        assert!(!parses_nothing(dir.path()));
+
+        fs::write(src.join("lib.rs"), "type Parsed<'a> = parse::Parsed<'a>;").unwrap();
+        fs::write(src.join("parse.rs"), "pub type Parsed<'a> = Vec<&'a str>;").unwrap();
+        assert!(!parses_nothing(dir.path()));
+
+        fs::write(src.join("lib.rs"), "type Parsed<'a> = &'a str;").unwrap();
+        assert!(parses_nothing(dir.path()));
+    }
+
+    #[test]
+    fn test_parse_duration_ns() {
+        assert_eq!(parse_duration_ns("12 ns"), Some(12.0));
+        assert_eq!(parse_duration_ns("1.5 s"), Some(1_500_000_000.0));
+        assert_eq!(parse_duration_ns("1.5 KB"), None);
+    }
+}
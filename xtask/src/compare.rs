@@ -0,0 +1,116 @@
+use anyhow::{bail, Result};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use crate::{input::check_input_file, solve::PartOutput, table::print_table, util::day_numbers};
+
+#[derive(Args)]
+pub struct CompareArgs {
+    /// only compare this day, every day with a folder by default
+    day: Option<u8>,
+    /// only compare this part of the day
+    part: Option<u8>,
+}
+
+/// The outcome of one language solving a part, `Err` holding why it gave no answer.
+type Solved = Result<(String, f64), String>;
+
+/// Solves every selected part in both languages and checks they print the same answer.
+///
+/// `input_for` gives the real input of a `(day, part)`, `rust` and `go` solve it.
+pub fn compare_langs(
+    project_root: &Path,
+    args: &CompareArgs,
+    input_for: impl Fn(u8, u8) -> PathBuf,
+    rust: impl Fn(u8, u8, &Path) -> Result<PartOutput>,
+    go: impl Fn(u8, u8, &Path) -> Result<PartOutput>,
+) -> Result<()> {
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => day_numbers(project_root)?,
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut rows = vec![];
+    let mut mismatches = vec![];
+    for day in days {
+        for &part in &parts {
+            let input = input_for(day, part);
+            if check_input_file(&input).is_err() {
+                rows.push(row(day, part, "", "no input"));
+                continue;
+            }
+
+            eprintln!("comparing day-{day:02} part{part}");
+            let rust = solve(&rust, day, part, &input);
+            let go = solve(&go, day, part, &input);
+            match (&rust, &go) {
+                (Ok((rust, _)), Ok((go, _))) if rust == go => {
+                    rows.push(row(day, part, rust, "same"))
+                }
+                (Ok(_), Ok(_)) => {
+                    rows.push(row(day, part, "", "differ"));
+                    mismatches.push((day, part, rust, go));
+                }
+                _ => {
+                    rows.push(row(day, part, "", "failed"));
+                    mismatches.push((day, part, rust, go));
+                }
+            }
+        }
+    }
+
+    print_table(&["day", "part", "answer", "status"], &rows);
+
+    for (day, part, rust, go) in &mismatches {
+        println!("\nday-{day:02} part{part}");
+        for (lang, solved) in [("rust", rust), ("go", go)] {
+            match solved {
+                Ok((answer, elapsed_ms)) => println!("  {lang:<4} {elapsed_ms:>10.2}ms  {answer}"),
+                Err(reason) => println!("  {lang:<4} {:>12}  {reason}", "failed"),
+            }
+        }
+    }
+
+    if !mismatches.is_empty() {
+        bail!(
+            "{} part(s) did not agree between rust and go",
+            mismatches.len()
+        );
+    }
+    Ok(())
+}
+
+fn solve(
+    run: impl Fn(u8, u8, &Path) -> Result<PartOutput>,
+    day: u8,
+    part: u8,
+    input: &Path,
+) -> Solved {
+    let output = run(day, part, input).map_err(|e| format!("{e:#}"))?;
+    match (output.success, &output.answer) {
+        (true, Some(answer)) => Ok((answer.clone(), output.elapsed.as_secs_f64() * 1000.0)),
+        (true, None) => Err("printed no answer".to_string()),
+        (false, _) if output.is_todo() => Err("not implemented yet".to_string()),
+        (false, _) => Err(output
+            .stderr
+            .trim()
+            .lines()
+            .next_back()
+            .unwrap_or("exited unsuccessfully")
+            .to_string()),
+    }
+}
+
+fn row(day: u8, part: u8, answer: &str, status: &str) -> Vec<String> {
+    vec![
+        format!("{day:02}"),
+        part.to_string(),
+        answer.to_string(),
+        status.to_string(),
+    ]
+}
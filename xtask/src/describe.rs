@@ -0,0 +1,241 @@
+use anyhow::{bail, Result};
+use std::{fs, path::Path};
+
+use clap::Args;
+use scraper::{ElementRef, Html, Node, Selector};
+
+use crate::{
+    aoc::{AocArgs, Client},
+    year::Year,
+};
+
+/// How advent of code titles the second half of a puzzle.
+const PART_TWO_TITLE: &str = "--- Part Two ---";
+
+#[derive(Args)]
+pub struct DescribeArgs {
+    day: u8,
+    #[command(flatten)]
+    aoc: AocArgs,
+}
+
+/// A puzzle page boiled down to what is worth keeping next to the solution.
+#[derive(Debug, PartialEq)]
+pub struct Puzzle {
+    pub markdown: String,
+    pub parts: usize,
+    /// The contents of every `<pre><code>` block, the usual home of examples.
+    pub examples: Vec<String>,
+}
+
+pub fn describe(year: &Year, args: &DescribeArgs) -> Result<()> {
+    let day_dir = year.day_dir(args.day);
+    let readme = day_dir.join("README.md");
+    if fs::read_to_string(&readme).is_ok_and(|readme| readme.contains(PART_TWO_TITLE)) {
+        println!("{} already has both parts", readme.display());
+        return Ok(());
+    }
+
+    let client = Client::from_args(&args.aoc)?;
+    let html = client.puzzle(year.year, args.day)?;
+    let puzzle = Puzzle::parse(&html, client.base_url())?;
+
+    fs::create_dir_all(&day_dir)?;
+    fs::write(&readme, &puzzle.markdown)?;
+    println!("wrote {} parts to {}", puzzle.parts, readme.display());
+
+    for candidate in save_candidates(&day_dir.join("examples"), &puzzle.examples)? {
+        println!("candidate example {}", candidate.display());
+    }
+    Ok(())
+}
+
+impl Puzzle {
+    pub fn parse(html: &str, base_url: &str) -> Result<Self> {
+        let document = Html::parse_document(html);
+        let articles = Selector::parse("article.day-desc").expect("valid selector");
+        let code_blocks = Selector::parse("pre > code").expect("valid selector");
+
+        let mut markdown = String::new();
+        let mut parts = 0;
+        let mut examples: Vec<String> = vec![];
+        for article in document.select(&articles) {
+            parts += 1;
+            markdown.push_str(&blocks(article, base_url));
+            for code in article.select(&code_blocks) {
+                let example = code.text().collect::<String>();
+                if !examples.contains(&example) {
+                    examples.push(example);
+                }
+            }
+        }
+        if parts == 0 {
+            bail!("no puzzle description on the page, is the session still valid?");
+        }
+
+        Ok(Self {
+            markdown: markdown.trim_end().to_string() + "\n",
+            parts,
+            examples,
+        })
+    }
+}
+
+/// Renders the block level children of `element`, each followed by a blank line.
+fn blocks(element: ElementRef, base_url: &str) -> String {
+    let mut markdown = String::new();
+    for child in element.children() {
+        let Some(child) = ElementRef::wrap(child) else {
+            continue;
+        };
+        match child.value().name() {
+            "h2" => markdown.push_str(&format!("## {}\n\n", inline(child, base_url).trim())),
+            "p" => markdown.push_str(&format!("{}\n\n", inline(child, base_url).trim())),
+            "pre" => markdown.push_str(&format!(
+                "```\n{}\n```\n\n",
+                child.text().collect::<String>().trim_end()
+            )),
+            "ul" | "ol" => {
+                for item in child.children().filter_map(ElementRef::wrap) {
+                    markdown.push_str(&format!("- {}\n", inline(item, base_url).trim()));
+                }
+                markdown.push('\n');
+            }
+            _ => markdown.push_str(&format!("{}\n\n", inline(child, base_url).trim())),
+        }
+    }
+    markdown
+}
+
+/// Renders the text of `element` with its emphasis, code and links.
+fn inline(element: ElementRef, base_url: &str) -> String {
+    let mut markdown = String::new();
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => markdown.push_str(&text.replace('\n', " ")),
+            Node::Element(_) => {
+                let child = ElementRef::wrap(child).expect("an element node");
+                let text = inline(child, base_url);
+                match child.value().name() {
+                    "code" => markdown.push_str(&format!("`{}`", child.text().collect::<String>())),
+                    "em" => markdown.push_str(&format!("**{text}**")),
+                    "a" => match child.value().attr("href") {
+                        Some(href) if href.starts_with('/') => {
+                            markdown.push_str(&format!("[{text}]({base_url}{href})"))
+                        }
+                        Some(href) => markdown.push_str(&format!("[{text}]({href})")),
+                        None => markdown.push_str(&text),
+                    },
+                    _ => markdown.push_str(&text),
+                }
+            }
+            _ => {}
+        }
+    }
+    markdown
+}
+
+/// Writes every example that is not already in `examples_dir` as
+/// `candidate-NN.txt`, for `cargo xtask example add` to pick from.
+fn save_candidates(examples_dir: &Path, examples: &[String]) -> Result<Vec<std::path::PathBuf>> {
+    fs::create_dir_all(examples_dir)?;
+    let existing = fs::read_dir(examples_dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+        .collect::<Vec<_>>();
+
+    let mut saved = vec![];
+    let mut number = 1;
+    for example in examples {
+        if existing.iter().any(|e| e.trim_end() == example.trim_end()) {
+            continue;
+        }
+        let path = loop {
+            let path = examples_dir.join(format!("candidate-{number:02}.txt"));
+            number += 1;
+            if !path.exists() {
+                break path;
+            }
+        };
+        fs::write(&path, example)?;
+        saved.push(path);
+    }
+    Ok(saved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{mock::MockServer, Throttle};
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    const PART1: &str = include_str!("../fixtures/day-01-part1.html");
+    const PART2: &str = include_str!("../fixtures/day-01-part2.html");
+
+    #[test]
+    fn test_parse_part1() {
+        let puzzle = Puzzle::parse(PART1, "https://adventofcode.com").unwrap();
+        assert_eq!(puzzle.parts, 1);
+        assert_eq!(
+            puzzle.markdown,
+            "## --- Day 1: Lantern Count ---
+
+The elves have strung up **lanterns** along the path, and each row of the [manual](https://adventofcode.com/2023/about) lists how bright they are.
+
+For example:
+
+```
+3 < 4
+12
+7
+```
+
+Add up the first number of every row. In this example, that is `3`, `12` and `7`, so the total is `22`.
+
+- Rows can be empty.
+- Numbers never start with `0`.
+
+**What is the total?**
+"
+        );
+        assert_eq!(puzzle.examples, vec!["3 < 4\n12\n7\n"]);
+    }
+
+    #[test]
+    fn test_parse_part2() {
+        let puzzle = Puzzle::parse(PART2, "https://adventofcode.com").unwrap();
+        assert_eq!(puzzle.parts, 2);
+        assert!(puzzle
+            .markdown
+            .ends_with("## --- Part Two ---\n\nNow multiply the last number of every row instead. For example:\n\n```\n2 3\n4\n```\n\nThat gives `12`.\n"));
+        assert!(!puzzle.markdown.contains("Your puzzle answer"));
+        assert_eq!(puzzle.examples, vec!["3 < 4\n12\n7\n", "2 3\n4\n"]);
+    }
+
+    #[test]
+    fn test_parse_logged_out() {
+        assert!(Puzzle::parse("<html><body><main></main></body></html>", "").is_err());
+    }
+
+    #[test]
+    fn test_save_candidates() {
+        let server = MockServer::start(vec![("/2023/day/1", 200, PART2.to_string())]);
+        let dir = tempfile::tempdir().unwrap();
+        let client = Client::new(
+            &server.url,
+            "abc123",
+            Throttle::new(dir.path().join("last-request"), Duration::ZERO),
+        );
+        let puzzle = Puzzle::parse(&client.puzzle(2023, 1).unwrap(), client.base_url()).unwrap();
+
+        let examples_dir = dir.path().join("examples");
+        fs::create_dir_all(&examples_dir).unwrap();
+        fs::write(examples_dir.join("01.txt"), "3 < 4\n12\n7\n").unwrap();
+        let saved = save_candidates(&examples_dir, &puzzle.examples).unwrap();
+        assert_eq!(saved, vec![examples_dir.join("candidate-01.txt")]);
+        assert!(save_candidates(&examples_dir, &puzzle.examples)
+            .unwrap()
+            .is_empty());
+    }
+}
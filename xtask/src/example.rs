@@ -0,0 +1,135 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use clap::{Args, Subcommand};
+use toml_edit::{value, DocumentMut, Item, Table};
+
+use crate::year::Year;
+
+/// Expected answers of a day's examples, next to the examples themselves.
+pub const EXPECTED_FILE: &str = "expected.toml";
+
+#[derive(Args)]
+pub struct ExampleArgs {
+    #[command(subcommand)]
+    command: ExampleCommands,
+}
+
+#[derive(Subcommand)]
+enum ExampleCommands {
+    /// store the example read from stdin with what a part should answer for it
+    Add(AddArgs),
+}
+
+#[derive(Args)]
+struct AddArgs {
+    day: u8,
+    part: u8,
+    /// what the part should answer for this example
+    #[arg(short, long)]
+    expected: String,
+}
+
+pub fn example(year: &Year, args: &ExampleArgs) -> Result<()> {
+    match &args.command {
+        ExampleCommands::Add(args) => {
+            let mut example = String::new();
+            io::stdin()
+                .read_to_string(&mut example)
+                .context("read example from stdin")?;
+            let examples_dir = year.day_dir(args.day).join("examples");
+            let number = add(&examples_dir, &example, args.part, &args.expected)?;
+            println!(
+                "example {number:02} of day-{:02} part{} expects {}",
+                args.day, args.part, args.expected
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Stores `example` as `NN.txt` in `examples_dir`, reusing the number of an
+/// identical example that is already there, and records what `part` should
+/// answer for it.
+pub fn add(examples_dir: &Path, example: &str, part: u8, expected: &str) -> Result<u8> {
+    let example = example.trim_end().to_string() + "\n";
+    if example.trim().is_empty() {
+        bail!("the example is empty, pipe it into stdin");
+    }
+
+    fs::create_dir_all(examples_dir)?;
+    let mut numbers = example_numbers(examples_dir)?;
+    numbers.sort();
+    let existing = numbers.iter().copied().find(|number| {
+        fs::read_to_string(examples_dir.join(format!("{number:02}.txt")))
+            .is_ok_and(|contents| contents == example)
+    });
+    let number = match existing {
+        Some(number) => number,
+        None => {
+            let number = numbers.last().map_or(1, |last| last + 1);
+            fs::write(examples_dir.join(format!("{number:02}.txt")), &example)?;
+            number
+        }
+    };
+
+    let path = examples_dir.join(EXPECTED_FILE);
+    let contents = if path.exists() {
+        fs::read_to_string(&path)?
+    } else {
+        String::new()
+    };
+    let mut doc = contents
+        .parse::<DocumentMut>()
+        .with_context(|| format!("parse {}", path.display()))?;
+    doc.entry(&format!("{number:02}"))
+        .or_insert(Item::Table(Table::new()))[&format!("part{part}")] = value(expected);
+    fs::write(&path, doc.to_string())?;
+
+    Ok(number)
+}
+
+/// Numbers of the `NN.txt` examples in `examples_dir`.
+fn example_numbers(examples_dir: &Path) -> Result<Vec<u8>> {
+    Ok(fs::read_dir(examples_dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_suffix(".txt"))
+                .and_then(|number| number.parse::<u8>().ok())
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_add() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let examples_dir = dir.path().join("examples");
+
+        assert_eq!(add(&examples_dir, "1abc2\npqr3stu8vwx\n", 1, "142")?, 1);
+        assert_eq!(add(&examples_dir, "two1nine\n", 2, "29")?, 2);
+        assert_eq!(add(&examples_dir, "1abc2\npqr3stu8vwx", 2, "142")?, 1);
+        assert!(add(&examples_dir, "\n", 1, "0").is_err());
+
+        assert_eq!(
+            fs::read_to_string(examples_dir.join("01.txt"))?,
+            "1abc2\npqr3stu8vwx\n"
+        );
+        assert_eq!(
+            fs::read_to_string(examples_dir.join(EXPECTED_FILE))?,
+            "[01]\npart1 = \"142\"\npart2 = \"142\"\n\n[02]\npart2 = \"29\"\n"
+        );
+        Ok(())
+    }
+}
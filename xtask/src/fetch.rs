@@ -0,0 +1,90 @@
+use anyhow::Result;
+use std::{fs, path::PathBuf};
+
+use clap::Args;
+
+use crate::{
+    aoc::{AocArgs, Client},
+    input::is_placeholder,
+    year::Year,
+};
+
+#[derive(Args)]
+pub struct FetchArgs {
+    day: u8,
+    #[command(flatten)]
+    aoc: AocArgs,
+}
+
+pub fn fetch(year: &Year, args: &FetchArgs) -> Result<()> {
+    let inputs = input_files(year, args.day);
+    if is_downloaded(&inputs) {
+        println!("day-{:02} input is already downloaded", args.day);
+        return Ok(());
+    }
+    let client = Client::from_args(&args.aoc)?;
+    download(&client, year.year, args.day, &inputs)?;
+    println!("downloaded day-{:02} input", args.day);
+    Ok(())
+}
+
+/// Every file a day reads its real input from, both parts share one download.
+fn input_files(year: &Year, day: u8) -> Vec<PathBuf> {
+    let mut inputs = vec![year.input(day, 1), year.input(day, 2)];
+    inputs.dedup();
+    inputs
+}
+
+/// The inputs on disk are the cache, anything but a placeholder is kept.
+fn is_downloaded(inputs: &[PathBuf]) -> bool {
+    inputs
+        .iter()
+        .all(|input| fs::read_to_string(input).is_ok_and(|contents| !is_placeholder(&contents)))
+}
+
+fn download(client: &Client, year: u16, day: u8, inputs: &[PathBuf]) -> Result<()> {
+    let input = client.input(year, day)?;
+    for path in inputs {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, &input)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{mock::MockServer, Throttle};
+    use crate::year::Layout;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_fetch_once() {
+        let root = tempfile::tempdir().unwrap();
+        let year = Year {
+            year: 2023,
+            root: root.path().to_path_buf(),
+            layout: Layout::Flat,
+        };
+        fs::create_dir_all(year.day_dir(1)).unwrap();
+        fs::write(year.input(1, 1), "input1 file!\n").unwrap();
+
+        let server = MockServer::start(vec![("/2023/day/1/input", 200, "1abc2\n".to_string())]);
+        let client = Client::new(
+            &server.url,
+            "abc123",
+            Throttle::new(root.path().join("last-request"), Duration::ZERO),
+        );
+        let inputs = input_files(&year, 1);
+
+        assert!(!is_downloaded(&inputs));
+        download(&client, 2023, 1, &inputs).unwrap();
+        assert!(is_downloaded(&inputs));
+        assert_eq!(fs::read_to_string(year.input(1, 1)).unwrap(), "1abc2\n");
+        assert_eq!(fs::read_to_string(year.input(1, 2)).unwrap(), "1abc2\n");
+        assert_eq!(server.requests.lock().unwrap().len(), 1);
+    }
+}
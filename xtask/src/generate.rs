@@ -0,0 +1,205 @@
+use anyhow::{bail, Context, Result};
+use std::{fs, path::Path};
+
+use clap::{Args, ValueEnum};
+
+use crate::util::get_day_string;
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// day you want to generate. ex: 1 for day-01
+    pub day_num: u8,
+    /// write the template over a day that already exists
+    #[arg(long)]
+    pub force: bool,
+    /// start from a parser for this input shape instead of one that splits lines
+    #[arg(long, value_enum)]
+    pub kind: Option<Kind>,
+}
+
+/// The input shapes a template has a starter for, in its `kinds/<kind>/parse.rs`.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Kind {
+    /// a grid of characters
+    Grid,
+    /// `A = (B, C)` adjacency lines
+    Graph,
+    /// rows of whitespace separated numbers
+    Lines,
+    /// blocks of lines separated by blank lines
+    Blocks,
+}
+
+impl Kind {
+    fn dir_name(self) -> &'static str {
+        match self {
+            Kind::Grid => "grid",
+            Kind::Graph => "graph",
+            Kind::Lines => "lines",
+            Kind::Blocks => "blocks",
+        }
+    }
+}
+
+/// Holds the `--kind` starters inside a template, it is never copied as is.
+const KINDS_DIR: &str = "kinds";
+
+/// The placeholders every template can use, like `{{day_dash}}`.
+pub fn template_vars(day: u8) -> Vec<(&'static str, String)> {
+    let day_dash = get_day_string(day);
+    vec![
+        ("day_num", day.to_string()),
+        ("day_under", day_dash.replace('-', "_")),
+        ("day_dash", day_dash),
+    ]
+}
+
+/// Copies every file of `template` into `dest`, filling in the placeholders.
+///
+/// Refuses to touch a `dest` that already exists unless `force` is set.
+pub fn render_template(
+    template: &Path,
+    dest: &Path,
+    vars: &[(&str, String)],
+    force: bool,
+) -> Result<()> {
+    if dest.exists() && !force {
+        bail!(
+            "{} already exists, pass --force to generate over it",
+            dest.display()
+        );
+    }
+    render_dir(template, dest, vars)
+}
+
+fn render_dir(template: &Path, dest: &Path, vars: &[(&str, String)]) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in
+        fs::read_dir(template).with_context(|| format!("read template {}", template.display()))?
+    {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_name() == KINDS_DIR {
+            continue;
+        }
+        let dest = dest.join(entry.file_name());
+        if path.is_dir() {
+            render_dir(&path, &dest, vars)?;
+            continue;
+        }
+        let contents = fs::read_to_string(&path)?;
+        let rendered =
+            render(&contents, vars).with_context(|| format!("render {}", path.display()))?;
+        fs::write(&dest, rendered)?;
+    }
+    Ok(())
+}
+
+/// Writes the parser of `kind` over the one of the crate rendered at `dest`.
+pub fn render_kind(
+    template: &Path,
+    kind: Kind,
+    dest: &Path,
+    vars: &[(&str, String)],
+) -> Result<()> {
+    let starter = template
+        .join(KINDS_DIR)
+        .join(kind.dir_name())
+        .join("parse.rs");
+    let contents = fs::read_to_string(&starter)
+        .with_context(|| format!("read starter {}", starter.display()))?;
+    let rendered =
+        render(&contents, vars).with_context(|| format!("render {}", starter.display()))?;
+    fs::write(dest.join("src").join("parse.rs"), rendered)?;
+    Ok(())
+}
+
+/// Replaces every `{{name}}` in `template` with its value from `vars`.
+pub fn render(template: &str, vars: &[(&str, String)]) -> Result<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find("}}") else {
+            bail!("unclosed `{{{{` in template");
+        };
+        let name = rest[start + 2..start + len].trim();
+        let Some((_, value)) = vars.iter().find(|(var, _)| *var == name) else {
+            bail!("unknown template placeholder `{name}`");
+        };
+        rendered.push_str(value);
+        rest = &rest[start + len + 2..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render() {
+        let vars = template_vars(7);
+        assert_eq!(
+            render(
+                "use {{day_under}}::*; // {{ day_dash }} is day {{day_num}}",
+                &vars
+            )
+            .unwrap(),
+            "use day_07::*; // day-07 is day 7"
+        );
+        assert_eq!(
+            render("fn f() { {input} }", &vars).unwrap(),
+            "fn f() { {input} }"
+        );
+        assert!(render("{{nope}}", &vars).is_err());
+        assert!(render("{{day_num", &vars).is_err());
+    }
+
+    #[test]
+    fn test_render_kind() {
+        for (year, template, day_dir) in
+            [("2023", "template", ""), ("2024", "template-rust", "rust")]
+        {
+            let dir = scratch::workspace(year, &Path::new("day-*").join(day_dir).to_string_lossy());
+            let mut packages = vec![];
+            for (day, kind) in (1..).zip(Kind::value_variants()) {
+                let dest = dir.path().join(get_day_string(day)).join(day_dir);
+                scratch::generate(year, template, day, &dest);
+                render_kind(
+                    &scratch::year_root(year).join(template),
+                    *kind,
+                    &dest,
+                    &template_vars(day),
+                )
+                .unwrap();
+                packages.extend(["--package".to_string(), get_day_string(day)]);
+            }
+
+            // each starter's own test checks the shape of what it parses
+            let output = std::process::Command::new(scratch::cargo())
+                .arg("test")
+                .args(&packages)
+                .arg("parse::tests")
+                .current_dir(dir.path())
+                .output()
+                .unwrap();
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            assert!(
+                output.status.success(),
+                "{year}: {stdout}{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            assert_eq!(
+                stdout
+                    .matches("test parse::tests::test_parse ... ok")
+                    .count(),
+                4,
+                "{year}"
+            );
+        }
+    }
+}
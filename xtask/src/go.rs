@@ -0,0 +1,320 @@
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::bench::{compare, format_ns, record_rows, BenchArgs, BenchCommands, BenchRow};
+use crate::generate::{render_template, template_vars};
+use crate::guesses::guess;
+use crate::limits::{run_with_limits, LimitArgs};
+use crate::run_all::run_all;
+use crate::solve::{timed_output, PartOutput};
+use crate::util::{exit_on_failure, get_day_string, get_part_string, setup_day_folder};
+use crate::verify::verify;
+use crate::year::Year;
+use crate::XTaskCommands;
+
+/// How many times `go test` repeats a benchmark, each repetition is one sample.
+const GO_BENCH_COUNT: u8 = 10;
+
+pub fn handle_go(command: XTaskCommands, year: &Year) -> anyhow::Result<()> {
+    let project_root = &year.root;
+    match command {
+        XTaskCommands::Generate(args) => {
+            if args.kind.is_some() {
+                anyhow::bail!("--kind starters are only there for rust");
+            }
+            let day = args.day_num;
+            let dir = year.day_dir(day);
+            render_template(
+                &year.go_template(),
+                &dir.join("go"),
+                &template_vars(day),
+                args.force,
+            )?;
+            setup_day_folder(&dir)?;
+            println!("generated {}/go", get_day_string(day));
+        }
+        XTaskCommands::Run(args) => {
+            let day_string = get_day_string(args.day_part.day);
+            let part_string = get_part_string(args.day_part.part);
+            let input = args.input.resolve(
+                &year.day_dir(args.day_part.day),
+                &year.input_name(args.day_part.part),
+            )?;
+            if args.limits.is_set() {
+                // under `go run` the limits would apply to go, not the solution
+                let binary = build_go_part(project_root, args.day_part.day, args.day_part.part)?;
+                return run_with_limits(Command::new(binary).arg(input.as_arg()), &args.limits);
+            }
+            let path = PathBuf::new()
+                .join(&day_string)
+                .join("go")
+                .join(&part_string)
+                .join(format!("{}.go", part_string));
+            exit_on_failure(
+                Command::new("go")
+                    .args(vec![
+                        "run",
+                        path.to_string_lossy().as_ref(),
+                        input.as_arg().as_ref(),
+                    ])
+                    .current_dir(project_root)
+                    .status()?,
+            );
+        }
+        XTaskCommands::Test(args) => {
+            if args.junit.is_some() || args.json.is_some() {
+                anyhow::bail!("test reports are only written for rust, go test has its own -json");
+            }
+            let package = match (args.day, args.part) {
+                (None, _) => "./...".to_string(),
+                (Some(day), Some(part)) => {
+                    format!("./{}/go/{}", get_day_string(day), get_part_string(part))
+                }
+                (Some(day), None) => format!("./{}/go/...", get_day_string(day)),
+            };
+            exit_on_failure(
+                Command::new("go")
+                    .args(["test", "-v", &package])
+                    .args(args.opts)
+                    .current_dir(project_root)
+                    .status()?,
+            );
+        }
+        XTaskCommands::Verify(args) => {
+            verify(project_root, &args, |day, part| {
+                run_go_part(project_root, day, part, &year.input(day, part))
+            })?;
+        }
+        XTaskCommands::Guess(args) => guess(project_root, &args)?,
+        XTaskCommands::Bench(BenchArgs {
+            command: Some(BenchCommands::Compare(args)),
+            ..
+        }) => compare(project_root, "go", &args)?,
+        XTaskCommands::Bench(args) => {
+            let (Some(day), Some(part)) = (args.day, args.part) else {
+                unreachable!("clap requires day and part without a subcommand");
+            };
+            let part_string = get_part_string(part);
+            let output = Command::new("go")
+                .stdout(Stdio::piped())
+                .current_dir(project_root)
+                .args([
+                    "test",
+                    "-run",
+                    "^$",
+                    "-bench",
+                    &format!("^Benchmark{}$", capitalize(&part_string)),
+                    "-count",
+                    &GO_BENCH_COUNT.to_string(),
+                    &format!("./{}/go/{part_string}", get_day_string(day)),
+                ])
+                .output()?;
+
+            let stdout = String::from_utf8(output.stdout)?;
+            if !output.status.success() {
+                eprintln!(
+                    "benchmark failed, results will not be written: {stdout}{}",
+                    String::from_utf8(output.stderr)?
+                );
+                std::process::exit(1);
+            }
+            let rows = parse_go_bench(&stdout);
+            for record in record_rows(project_root, "go", day, &rows, args.save_baseline)? {
+                println!(
+                    "recorded {} {} median {}",
+                    get_day_string(record.day),
+                    record.bench_name(),
+                    format_ns(record.median_ns)
+                );
+            }
+        }
+        XTaskCommands::Build(args) => {
+            let parts = match args.part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            for part in parts {
+                let binary = build_go_part(project_root, args.day, part)?;
+                println!("built {}", binary.display());
+            }
+        }
+        XTaskCommands::RunAll(args) => {
+            run_all(
+                project_root,
+                &args,
+                |day, part| year.input(day, part),
+                |day, part, input| run_built_go_part(project_root, day, part, input, &args.limits),
+            )?;
+        }
+        XTaskCommands::List => anyhow::bail!("`list` is not supported for go"),
+        XTaskCommands::Alloc(_) => anyhow::bail!("`alloc` is not supported for go"),
+        XTaskCommands::Status(_) => anyhow::bail!("`status` is not supported for go"),
+        XTaskCommands::Watch(_) => anyhow::bail!("`watch` is not supported for go"),
+        XTaskCommands::Compare(_)
+        | XTaskCommands::Example(_)
+        | XTaskCommands::Fetch(_)
+        | XTaskCommands::Describe(_) => {
+            unreachable!("main handles these the same for every language")
+        }
+    }
+    Ok(())
+}
+
+/// Compiles a part ahead of time so timing it does not include `go run` building it.
+pub fn build_go_part(project_root: &Path, day: u8, part: u8) -> anyhow::Result<PathBuf> {
+    let day_string = get_day_string(day);
+    let part_string = get_part_string(part);
+    let binary = project_root.join("target").join("go").join(format!(
+        "{day_string}-{part_string}{}",
+        std::env::consts::EXE_SUFFIX
+    ));
+    let status = Command::new("go")
+        .arg("build")
+        .arg("-o")
+        .arg(&binary)
+        .arg(format!("./{day_string}/go/{part_string}"))
+        .current_dir(project_root)
+        .status()
+        .context("run `go build`, is go installed?")?;
+    if !status.success() {
+        anyhow::bail!("building {day_string} {part_string} with go failed");
+    }
+    Ok(binary)
+}
+
+/// Builds a part and times only running the binary, unlike `run_go_part`.
+pub fn run_built_go_part(
+    project_root: &Path,
+    day: u8,
+    part: u8,
+    input: &Path,
+    limits: &LimitArgs,
+) -> anyhow::Result<PartOutput> {
+    let binary = build_go_part(project_root, day, part)?;
+    timed_output(Command::new(binary).arg(input), limits)
+}
+
+pub fn run_go_part(
+    project_root: &Path,
+    day: u8,
+    part: u8,
+    input: &Path,
+) -> anyhow::Result<PartOutput> {
+    let part_string = get_part_string(part);
+    let path = PathBuf::new()
+        .join(get_day_string(day))
+        .join("go")
+        .join(&part_string)
+        .join(format!("{}.go", part_string));
+    timed_output(
+        Command::new("go")
+            .arg("run")
+            .arg(path)
+            .arg(input)
+            .current_dir(project_root),
+        &LimitArgs::default(),
+    )
+}
+
+/// Collects the ns/op of every `BenchmarkPartN` line `go test -bench` printed,
+/// one line per `-count` repetition, into one row per benchmark.
+pub fn parse_go_bench(output: &str) -> Vec<BenchRow> {
+    let mut samples: Vec<(String, Vec<f64>)> = vec![];
+    for line in output.lines() {
+        let mut fields = line.split_whitespace();
+        let Some(name) = fields.next().and_then(|n| n.strip_prefix("Benchmark")) else {
+            continue;
+        };
+        let fields = fields.collect::<Vec<_>>();
+        let Some(ns) = fields
+            .windows(2)
+            .find(|w| w[1] == "ns/op")
+            .and_then(|w| w[0].parse::<f64>().ok())
+        else {
+            continue;
+        };
+        // `go test` appends `-GOMAXPROCS` to the name, `Part1-8` is `part1`
+        let name = name
+            .rsplit_once('-')
+            .map_or(name, |(name, _)| name)
+            .to_lowercase();
+        match samples.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, times)) => times.push(ns),
+            None => samples.push((name, vec![ns])),
+        }
+    }
+
+    samples
+        .into_iter()
+        .map(|(name, mut times)| {
+            times.sort_by(f64::total_cmp);
+            let mid = times.len() / 2;
+            let median = if times.len() % 2 == 0 {
+                (times[mid - 1] + times[mid]) / 2.0
+            } else {
+                times[mid]
+            };
+            BenchRow {
+                name,
+                fastest_ns: times[0],
+                slowest_ns: times[times.len() - 1],
+                median_ns: median,
+                mean_ns: times.iter().sum::<f64>() / times.len() as f64,
+                samples: times.len() as u64,
+            }
+        })
+        .collect()
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year::Layout;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_unsupported_command() {
+        let year = Year {
+            year: 2024,
+            root: PathBuf::from("does-not-exist"),
+            layout: Layout::Split,
+        };
+        let error = handle_go(XTaskCommands::List, &year).unwrap_err();
+        assert_eq!(error.to_string(), "`list` is not supported for go");
+    }
+
+    #[test]
+    fn test_parse_go_bench() {
+        let output = "goos: linux
+goarch: amd64
+pkg: github.com/pitoniak32/advent_of_code/2024/day-01/go/part1
+cpu: AMD Ryzen 7 5800X 8-Core Processor
+BenchmarkPart1-16    	    4213	    283000 ns/op
+a line the solution printed
+BenchmarkPart1-16    	    4100	    281000 ns/op	   12345 B/op	      12 allocs/op
+BenchmarkPart1-16    	    4300	    290000 ns/op
+PASS
+ok  	github.com/pitoniak32/advent_of_code/2024/day-01/go/part1	4.512s
+";
+        assert_eq!(
+            parse_go_bench(output),
+            vec![BenchRow {
+                name: "part1".to_string(),
+                fastest_ns: 281000.0,
+                slowest_ns: 290000.0,
+                median_ns: 283000.0,
+                mean_ns: 284666.6666666667,
+                samples: 3,
+            }]
+        );
+    }
+}
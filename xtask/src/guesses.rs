@@ -0,0 +1,240 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+};
+
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::{answers::Answers, table::print_table};
+
+pub const GUESSES_FILE: &str = "guesses.jsonl";
+
+#[derive(Args)]
+pub struct GuessArgs {
+    day: u8,
+    part: u8,
+    /// the answer you are about to submit, leave it out to show the history
+    answer: Option<String>,
+    /// what advent of code said about the answer once it was submitted
+    #[arg(short, long, value_enum)]
+    verdict: Option<Verdict>,
+    /// record the guess even when the history says it is wrong
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Pending,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Correct,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.to_possible_value().expect("no verdict is skipped");
+        write!(f, "{}", value.get_name())
+    }
+}
+
+impl Verdict {
+    fn is_rejected(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every guess made for one day and part, oldest first. A later entry for the
+/// same answer overrides the verdict of an earlier one.
+pub struct History {
+    guesses: Vec<Guess>,
+}
+
+impl History {
+    pub fn load(project_root: &Path, day: u8, part: u8) -> Result<Self> {
+        let path = project_root.join(GUESSES_FILE);
+        if !path.exists() {
+            return Ok(Self { guesses: vec![] });
+        }
+        let mut guesses: Vec<Guess> = vec![];
+        for (idx, line) in fs::read_to_string(&path)?.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let guess: Guess = serde_json::from_str(line)
+                .with_context(|| format!("parse {} line {}", path.display(), idx + 1))?;
+            if guess.day != day || guess.part != part {
+                continue;
+            }
+            guesses.retain(|g| g.answer != guess.answer);
+            guesses.push(guess);
+        }
+        Ok(Self { guesses })
+    }
+
+    fn verdict_of(&self, answer: &str) -> Option<Verdict> {
+        self.guesses
+            .iter()
+            .find(|g| g.answer == answer)
+            .map(|g| g.verdict)
+    }
+
+    /// The highest answer known to be too low and the lowest known to be too high.
+    pub fn bounds(&self) -> (Option<i128>, Option<i128>) {
+        let numbers_with = |verdict| {
+            self.guesses
+                .iter()
+                .filter(move |g| g.verdict == verdict)
+                .filter_map(|g| g.answer.parse::<i128>().ok())
+        };
+        (
+            numbers_with(Verdict::TooLow).max(),
+            numbers_with(Verdict::TooHigh).min(),
+        )
+    }
+
+    /// Reasons why `answer` is already known to be wrong.
+    pub fn check(&self, answer: &str, known: Option<&str>) -> Vec<String> {
+        let mut warnings = vec![];
+
+        if let Some(known) = known {
+            if known != answer {
+                warnings.push(format!("the correct answer is already known: {known}"));
+            }
+        }
+        if let Some(verdict) = self.verdict_of(answer).filter(|v| v.is_rejected()) {
+            warnings.push(format!("{answer} was already rejected as {verdict}"));
+        }
+        if let Ok(number) = answer.parse::<i128>() {
+            let (low, high) = self.bounds();
+            if let Some(low) = low.filter(|low| number <= *low) {
+                warnings.push(format!("{answer} is not above {low}, which was too low"));
+            }
+            if let Some(high) = high.filter(|high| number >= *high) {
+                warnings.push(format!("{answer} is not below {high}, which was too high"));
+            }
+        }
+
+        warnings
+    }
+}
+
+pub fn guess(project_root: &Path, args: &GuessArgs) -> Result<()> {
+    let history = History::load(project_root, args.day, args.part)?;
+    let answers = Answers::load(project_root)?;
+
+    let Some(answer) = &args.answer else {
+        print_history(&history);
+        return Ok(());
+    };
+
+    let verdict = args.verdict.unwrap_or(Verdict::Pending);
+    if verdict == Verdict::Pending {
+        let warnings = history.check(answer, answers.get(args.day, args.part));
+        for warning in &warnings {
+            eprintln!("warning: {warning}");
+        }
+        if !warnings.is_empty() && !args.force {
+            bail!("not recording {answer}, pass --force to record it anyway");
+        }
+    }
+
+    append(
+        project_root,
+        &Guess {
+            day: args.day,
+            part: args.part,
+            answer: answer.clone(),
+            verdict,
+        },
+    )?;
+    if verdict == Verdict::Correct {
+        Answers::record(project_root, args.day, args.part, answer)?;
+    }
+
+    println!(
+        "recorded {answer} as {verdict} for day-{:02} part{}",
+        args.day, args.part
+    );
+    Ok(())
+}
+
+fn append(project_root: &Path, guess: &Guess) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(project_root.join(GUESSES_FILE))?;
+    writeln!(file, "{}", serde_json::to_string(guess)?)?;
+    Ok(())
+}
+
+fn print_history(history: &History) {
+    let rows = history
+        .guesses
+        .iter()
+        .map(|g| vec![g.answer.clone(), g.verdict.to_string()])
+        .collect::<Vec<_>>();
+    print_table(&["answer", "verdict"], &rows);
+
+    let (low, high) = history.bounds();
+    let show = |bound: Option<i128>| bound.map_or("?".to_string(), |b| b.to_string());
+    println!("\nanswer is between {} and {}", show(low), show(high));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn history(guesses: &[(&str, Verdict)]) -> History {
+        History {
+            guesses: guesses
+                .iter()
+                .map(|(answer, verdict)| Guess {
+                    day: 1,
+                    part: 2,
+                    answer: answer.to_string(),
+                    verdict: *verdict,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        let history = history(&[
+            ("52859", Verdict::TooHigh),
+            ("52722", Verdict::TooLow),
+            ("52900", Verdict::TooHigh),
+            ("52000", Verdict::TooLow),
+            ("52800", Verdict::Wrong),
+        ]);
+        assert_eq!(history.bounds(), (Some(52722), Some(52859)));
+    }
+
+    #[test]
+    fn test_check() {
+        let history = history(&[("52859", Verdict::TooHigh), ("52722", Verdict::TooLow)]);
+
+        assert!(history.check("52840", None).is_empty());
+        assert_eq!(history.check("52859", None).len(), 2);
+        assert_eq!(history.check("52700", None).len(), 1);
+        assert_eq!(history.check("60000", None).len(), 1);
+        assert_eq!(history.check("52840", Some("52841")).len(), 1);
+        assert!(history.check("52840", Some("52840")).is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::{bail, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+
+#[derive(Args)]
+pub struct InputArgs {
+    /// read the puzzle input from this file instead of the day's input file, `-` reads stdin
+    #[arg(short, long, conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+    /// use the day's example input `examples/NN.txt`, defaults to the first example
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "1")]
+    pub example: Option<u8>,
+}
+
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// The value to hand to a solution binary, `-` meaning stdin.
+    pub fn as_arg(&self) -> String {
+        match self {
+            InputSource::Stdin => "-".to_string(),
+            InputSource::File(path) => path.to_string_lossy().to_string(),
+        }
+    }
+}
+
+impl InputArgs {
+    /// Works out which input to use for a day, falling back to `default_input`
+    /// inside `day_dir`, and makes sure a file source is actually usable.
+    pub fn resolve(&self, day_dir: &Path, default_input: &str) -> Result<InputSource> {
+        let path = match (&self.input, self.example) {
+            (Some(path), _) if path.as_os_str() == "-" => return Ok(InputSource::Stdin),
+            (Some(path), _) => path.clone(),
+            (None, Some(example)) => day_dir.join("examples").join(format!("{example:02}.txt")),
+            (None, None) => day_dir.join(default_input),
+        };
+        check_input_file(&path)?;
+        Ok(InputSource::File(path))
+    }
+}
+
+/// Errors when `path` is missing or still holds the placeholder written by `generate`.
+pub fn check_input_file(path: &Path) -> Result<()> {
+    if !path.is_file() {
+        bail!("input file {} does not exist", path.display());
+    }
+    if is_placeholder(&fs::read_to_string(path)?) {
+        bail!(
+            "input file {} still holds the generated placeholder, paste your puzzle input into it first",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// `generate` seeds new days with `input1 file!` / `input2 file!`.
+pub fn is_placeholder(contents: &str) -> bool {
+    contents
+        .trim()
+        .strip_prefix("input")
+        .and_then(|rest| rest.strip_suffix(" file!"))
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
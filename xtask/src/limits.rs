@@ -0,0 +1,235 @@
+use anyhow::{bail, Result};
+use std::{
+    fmt, fs,
+    process::{Child, Command, ExitStatus},
+    thread,
+    time::{Duration, Instant},
+};
+
+use clap::Args;
+
+use crate::util::exit_on_failure;
+
+/// The longest a limited run goes unchecked.
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Args, Clone, Copy, Debug, Default)]
+pub struct LimitArgs {
+    /// kill a solution that runs longer than this, e.g. `30s`, `500ms` or `2m`
+    #[arg(long, value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+    /// kill a solution whose resident memory grows past this, e.g. `2G` or `512M`, linux only
+    #[arg(long, value_parser = parse_bytes)]
+    pub max_mem: Option<u64>,
+}
+
+/// Why a solution was killed before it finished.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Killed {
+    Timeout,
+    Memory,
+}
+
+impl fmt::Display for Killed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            Killed::Timeout => "timed out",
+            Killed::Memory => "over memory",
+        };
+        write!(f, "{reason}")
+    }
+}
+
+impl LimitArgs {
+    pub fn is_set(&self) -> bool {
+        self.timeout.is_some() || self.max_mem.is_some()
+    }
+}
+
+/// Waits for `child` like [`Child::wait`], but kills it as soon as it goes
+/// over one of the `limits`.
+pub fn wait_limited(child: &mut Child, limits: &LimitArgs) -> Result<(ExitStatus, Option<Killed>)> {
+    if !limits.is_set() {
+        return Ok((child.wait()?, None));
+    }
+    if limits.max_mem.is_some() && !cfg!(target_os = "linux") {
+        child.kill()?;
+        child.wait()?;
+        bail!("--max-mem reads /proc, it only works on linux");
+    }
+
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok((status, None));
+        }
+        let killed = if limits
+            .timeout
+            .is_some_and(|timeout| start.elapsed() > timeout)
+        {
+            Some(Killed::Timeout)
+        } else if limits
+            .max_mem
+            .is_some_and(|max| resident_bytes(child.id()).is_some_and(|rss| rss > max))
+        {
+            Some(Killed::Memory)
+        } else {
+            None
+        };
+        if killed.is_some() {
+            // it may have exited since the last check, which is fine
+            let _ = child.kill();
+            return Ok((child.wait()?, killed));
+        }
+        // checking often while it is young keeps the timing of fast parts honest
+        let interval = (start.elapsed() / 10).clamp(Duration::from_micros(50), MAX_POLL_INTERVAL);
+        thread::sleep(interval);
+    }
+}
+
+/// Runs `command` with the terminal attached, failing when it had to be
+/// killed for going over `limits`.
+pub fn run_with_limits(command: &mut Command, limits: &LimitArgs) -> Result<()> {
+    let start = Instant::now();
+    let mut child = command.spawn()?;
+    match wait_limited(&mut child, limits)? {
+        (_, Some(killed)) => bail!("killed after {:.2?}, {killed}", start.elapsed()),
+        (status, None) => exit_on_failure(status),
+    }
+    Ok(())
+}
+
+/// The resident set of a running process, from the `VmRSS` line of its status.
+fn resident_bytes(pid: u32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))
+        .and_then(|rss| rss.trim().strip_suffix("kB"))
+        .and_then(|kb| kb.trim().parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+}
+
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (number, unit) = split_unit(s);
+    let number = number
+        .parse::<f64>()
+        .map_err(|_| format!("`{s}` does not start with a number"))?;
+    let secs = match unit {
+        "ms" => number / 1000.0,
+        "" | "s" => number,
+        "m" => number * 60.0,
+        "h" => number * 60.0 * 60.0,
+        _ => return Err(format!("unknown unit `{unit}`, use ms, s, m or h")),
+    };
+    if !secs.is_finite() {
+        return Err(format!("`{s}` is not a finite duration"));
+    }
+    if secs < 0.0 {
+        return Err(format!("`{s}` is negative"));
+    }
+    Duration::try_from_secs_f64(secs).map_err(|e| format!("`{s}`: {e}"))
+}
+
+fn parse_bytes(s: &str) -> Result<u64, String> {
+    let (number, unit) = split_unit(s);
+    let number = number
+        .parse::<f64>()
+        .map_err(|_| format!("`{s}` does not start with a number"))?;
+    let scale = match unit
+        .trim_end_matches(['B', 'b', 'i'])
+        .to_ascii_uppercase()
+        .as_str()
+    {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        _ => return Err(format!("unknown unit `{unit}`, use K, M or G")),
+    };
+    let bytes = number * scale as f64;
+    if !bytes.is_finite() {
+        return Err(format!("`{s}` is not a finite size"));
+    }
+    if bytes < 0.0 {
+        return Err(format!("`{s}` is negative"));
+    }
+    // `u64::MAX as f64` rounds up to 2^64, which is already one too many
+    if bytes >= u64::MAX as f64 {
+        return Err(format!("`{s}` is too big"));
+    }
+    Ok(bytes as u64)
+}
+
+/// Splits `1.5e3ms` into the number and the letters of its unit after it.
+fn split_unit(s: &str) -> (&str, &str) {
+    let number = s.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    (number, &s[number.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_limits() {
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_duration("soon").is_err());
+        assert!(parse_duration("3d").is_err());
+        assert_eq!(parse_duration("1e3ms"), Ok(Duration::from_secs(1)));
+        assert!(parse_duration("1e30").unwrap_err().contains("too big"));
+        assert_eq!(
+            parse_duration("1e400s"),
+            Err("`1e400s` is not a finite duration".to_string())
+        );
+        assert!(parse_duration("inf").is_err());
+        assert_eq!(parse_duration("-5s"), Err("`-5s` is negative".to_string()));
+
+        assert_eq!(parse_bytes("512M"), Ok(512 << 20));
+        assert_eq!(parse_bytes("2GiB"), Ok(2 << 30));
+        assert_eq!(parse_bytes("1.5k"), Ok(1536));
+        assert_eq!(parse_bytes("4096"), Ok(4096));
+        assert!(parse_bytes("lots").is_err());
+        assert_eq!(parse_bytes("-1G"), Err("`-1G` is negative".to_string()));
+        assert!(parse_bytes("nan").is_err());
+        assert!(parse_bytes("inf").is_err());
+        assert_eq!(
+            parse_bytes("1e400"),
+            Err("`1e400` is not a finite size".to_string())
+        );
+        assert_eq!(parse_bytes("1e30G"), Err("`1e30G` is too big".to_string()));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_wait_limited() {
+        let limits = LimitArgs {
+            timeout: Some(Duration::from_millis(100)),
+            max_mem: None,
+        };
+        let start = Instant::now();
+        let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+        let (status, killed) = wait_limited(&mut child, &limits).unwrap();
+        assert_eq!(killed, Some(Killed::Timeout));
+        assert!(!status.success());
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        let mut child = Command::new("true").spawn().unwrap();
+        let (status, killed) = wait_limited(&mut child, &limits).unwrap();
+        assert_eq!(killed, None);
+        assert!(status.success());
+
+        // anything at all is more than a byte
+        let limits = LimitArgs {
+            timeout: Some(Duration::from_secs(10)),
+            max_mem: Some(1),
+        };
+        let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+        let (_, killed) = wait_limited(&mut child, &limits).unwrap();
+        assert_eq!(killed, Some(Killed::Memory));
+    }
+}
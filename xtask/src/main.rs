@@ -0,0 +1,165 @@
+use anyhow::{bail, Result};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use self::{
+    alloc::AllocArgs,
+    bench::BenchArgs,
+    compare::{compare_langs, CompareArgs},
+    describe::{describe, DescribeArgs},
+    example::{example, ExampleArgs},
+    fetch::{fetch, FetchArgs},
+    generate::GenerateArgs,
+    go::{handle_go, run_built_go_part},
+    guesses::GuessArgs,
+    input::InputArgs,
+    limits::LimitArgs,
+    run_all::RunAllArgs,
+    rust::handle_rust,
+    solve::{build_runner, run_part},
+    status::StatusArgs,
+    test_all::TestArgs,
+    verify::VerifyArgs,
+    watch::WatchArgs,
+    year::Year,
+};
+
+mod alloc;
+mod answers;
+mod aoc;
+mod bench;
+mod compare;
+mod describe;
+mod example;
+mod fetch;
+mod generate;
+mod go;
+mod guesses;
+mod input;
+mod limits;
+mod register;
+mod run_all;
+mod rust;
+#[cfg(test)]
+mod scratch;
+mod solve;
+mod status;
+mod table;
+mod test_all;
+mod util;
+mod verify;
+mod watch;
+mod year;
+
+#[derive(Parser)]
+#[command(author, version, about)]
+struct XTaskCli {
+    #[command(subcommand)]
+    command: XTaskCommands,
+    /// year to work on, defaults to the year folder you are in
+    #[arg(short, long, global = true)]
+    year: Option<u16>,
+    /// language to work on, go is only there for years with go solutions
+    #[arg(short, long, global = true, value_enum, default_value_t = Lang::Rust)]
+    lang: Lang,
+}
+
+#[derive(Subcommand)]
+enum XTaskCommands {
+    Generate(GenerateArgs),
+    Run(RunArgs),
+    /// list every day and part the runner knows about
+    List,
+    /// run every day and part in release mode and time them
+    RunAll(RunAllArgs),
+    /// bench a part and add the results to `benches.jsonl`, or compare the latest results
+    Bench(BenchArgs),
+    /// run a part under a counting allocator and report its allocations and peak memory
+    Alloc(AllocArgs),
+    Build(DayArgs),
+    Test(TestArgs),
+    /// show every day's progress: todo!s, tests, inputs and the latest bench
+    Status(StatusArgs),
+    /// rerun a day's tests and then its solution every time its sources or inputs change
+    Watch(WatchArgs),
+    /// check solutions against the known answers in `answers.toml`
+    Verify(VerifyArgs),
+    /// record an answer you are about to submit, or its verdict, and check it against earlier guesses
+    Guess(GuessArgs),
+    /// run the rust and go solutions of a day on the same input and check they agree
+    Compare(CompareArgs),
+    /// manage a day's examples and the answers expected for them
+    Example(ExampleArgs),
+    /// download a day's puzzle input, once
+    Fetch(FetchArgs),
+    /// save a day's puzzle text to its README.md and its code blocks as candidate examples
+    Describe(DescribeArgs),
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Lang {
+    Go,
+    Rust,
+}
+
+#[derive(Args)]
+struct DayPartOptsArgs {
+    day: u8,
+    part: u8,
+    opts: Vec<String>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    day_part: DayPartOptsArgs,
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    limits: LimitArgs,
+}
+
+#[derive(Args)]
+struct DayArgs {
+    day: u8,
+    /// only this part, go builds and tests one part at a time and does both by default
+    #[arg(short, long)]
+    part: Option<u8>,
+    opts: Vec<String>,
+}
+
+fn main() -> Result<()> {
+    let cli = XTaskCli::parse();
+    let year = Year::resolve(cli.year)?;
+
+    if let XTaskCommands::Example(args) = &cli.command {
+        return example(&year, args);
+    }
+    if let XTaskCommands::Fetch(args) = &cli.command {
+        return fetch(&year, args);
+    }
+    if let XTaskCommands::Describe(args) = &cli.command {
+        return describe(&year, args);
+    }
+    if let XTaskCommands::Compare(args) = &cli.command {
+        if !year.has_go() {
+            bail!("{} has no go solutions to compare against", year.year);
+        }
+        let runner = build_runner(&year.root)?;
+        return compare_langs(
+            &year.root,
+            args,
+            |day, part| year.input(day, part),
+            |day, part, input| run_part(&runner, day, part, input, &LimitArgs::default()),
+            |day, part, input| {
+                run_built_go_part(&year.root, day, part, input, &LimitArgs::default())
+            },
+        );
+    }
+
+    match cli.lang {
+        Lang::Go if !year.has_go() => bail!("{} has no go solutions", year.year),
+        Lang::Go => handle_go(cli.command, &year),
+        Lang::Rust => handle_rust(cli.command, &year),
+    }
+}
@@ -0,0 +1,130 @@
+use anyhow::{anyhow, Context, Result};
+use std::{fs, path::Path};
+
+use toml_edit::{Array, DocumentMut};
+
+/// Adds a freshly generated day to the runner's dependencies and registry so
+/// `cargo r` can find it without any hand edits.
+pub fn register_day(runner_dir: &Path, crate_path: &str, day: u8) -> Result<()> {
+    let crate_name = format!("day-{day:02}");
+    let lib_name = crate_name.replace('-', "_");
+
+    let manifest_path = runner_dir.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let dependency = format!("{crate_name} = {{ path = \"{crate_path}\" }}");
+    if !manifest
+        .lines()
+        .any(|l| l.starts_with(&format!("{crate_name} =")))
+    {
+        fs::write(
+            &manifest_path,
+            insert_after_last(&manifest, &["day-", "util ="], &dependency)?,
+        )?;
+    }
+
+    let registry_path = runner_dir.join("src").join("registry.rs");
+    let registry = fs::read_to_string(&registry_path)?;
+    let registration = format!("    registry.register::<{lib_name}::Day>();");
+    if !registry.contains(&registration) {
+        fs::write(
+            &registry_path,
+            insert_after_last(
+                &registry,
+                &["    registry.register::<", "    let mut registry"],
+                &registration,
+            )?,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Adds `crate_path` to the members of the workspace in `workspace_root`,
+/// unless one of the member globs like `day-*` already covers it.
+pub fn add_workspace_member(workspace_root: &Path, crate_path: &str) -> Result<()> {
+    let manifest_path = workspace_root.join("Cargo.toml");
+    let mut manifest = fs::read_to_string(&manifest_path)?
+        .parse::<DocumentMut>()
+        .with_context(|| format!("parse {}", manifest_path.display()))?;
+    let members = manifest["workspace"]["members"]
+        .as_array_mut()
+        .ok_or_else(|| anyhow!("{} has no workspace members", manifest_path.display()))?;
+
+    if members
+        .iter()
+        .filter_map(|m| m.as_str())
+        .any(|member| glob_matches(member, crate_path))
+    {
+        return Ok(());
+    }
+    push_member(members, crate_path);
+    fs::write(&manifest_path, manifest.to_string())?;
+    Ok(())
+}
+
+/// Pushes `member` laid out like the members before it, one per line or not.
+fn push_member(members: &mut Array, member: &str) {
+    let decor = members.iter().last().map(|last| last.decor().clone());
+    members.push(member);
+    if let (Some(decor), Some(pushed)) = (decor, members.iter_mut().last()) {
+        *pushed.decor_mut() = decor;
+    }
+}
+
+/// Matches cargo style member globs where `*` stands for part of one path segment.
+fn glob_matches(pattern: &str, path: &str) -> bool {
+    let patterns = pattern.trim_end_matches('/').split('/').collect::<Vec<_>>();
+    let segments = path.trim_end_matches('/').split('/').collect::<Vec<_>>();
+    patterns.len() == segments.len()
+        && patterns
+            .iter()
+            .zip(segments)
+            .all(|(pattern, segment)| match pattern.split_once('*') {
+                Some((prefix, suffix)) => {
+                    segment.len() >= prefix.len() + suffix.len()
+                        && segment.starts_with(prefix)
+                        && segment.ends_with(suffix)
+                }
+                None => *pattern == segment,
+            })
+}
+
+/// Inserts `line` after the last line starting with the first prefix that matches anything.
+fn insert_after_last(contents: &str, prefixes: &[&str], line: &str) -> Result<String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let idx = prefixes
+        .iter()
+        .find_map(|prefix| lines.iter().rposition(|l| l.starts_with(prefix)))
+        .ok_or_else(|| anyhow!("no line starting with any of {prefixes:?} to insert after"))?;
+    lines.insert(idx + 1, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("day-*", "day-07"));
+        assert!(glob_matches("day-*/rust", "day-07/rust"));
+        assert!(glob_matches("runner", "runner"));
+        assert!(!glob_matches("day-*", "day-07/rust"));
+        assert!(!glob_matches("day-*/rust", "day-07/go"));
+        assert!(!glob_matches("util", "util-rust"));
+    }
+
+    #[test]
+    fn test_push_member() {
+        let mut manifest = "[workspace]\nmembers = [\n  \"runner\",\n  \"util\",\n]\n"
+            .parse::<DocumentMut>()
+            .unwrap();
+        let members = manifest["workspace"]["members"].as_array_mut().unwrap();
+        push_member(members, "day-07");
+        assert_eq!(
+            manifest.to_string(),
+            "[workspace]\nmembers = [\n  \"runner\",\n  \"util\",\n  \"day-07\",\n]\n"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::Result;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+use serde::Serialize;
+
+use crate::{
+    input::check_input_file,
+    limits::{Killed, LimitArgs},
+    solve::PartOutput,
+    table::print_table,
+    util::day_numbers,
+};
+
+#[derive(Args)]
+pub struct RunAllArgs {
+    /// print the results as json instead of a table
+    #[arg(long)]
+    json: bool,
+    #[command(flatten)]
+    pub limits: LimitArgs,
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RunStatus {
+    Ok,
+    Todo,
+    Failed,
+    NoInput,
+    TimedOut,
+    OverMemory,
+}
+
+impl fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            RunStatus::Ok => "ok",
+            RunStatus::Todo => "todo",
+            RunStatus::Failed => "failed",
+            RunStatus::NoInput => "no input",
+            RunStatus::TimedOut => "timed out",
+            RunStatus::OverMemory => "over memory",
+        };
+        write!(f, "{status}")
+    }
+}
+
+#[derive(Serialize)]
+pub struct RunResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed_ms: f64,
+    pub status: RunStatus,
+}
+
+/// Runs every part of every day, carrying on past parts that are still `todo!()` or fail.
+///
+/// `input_for` gives the real input of a `(day, part)` and `run` solves it.
+pub fn run_all(
+    project_root: &Path,
+    args: &RunAllArgs,
+    input_for: impl Fn(u8, u8) -> PathBuf,
+    run: impl Fn(u8, u8, &Path) -> Result<PartOutput>,
+) -> Result<()> {
+    let mut results = vec![];
+    for day in day_numbers(project_root)? {
+        for part in [1, 2] {
+            let input = input_for(day, part);
+            if check_input_file(&input).is_err() {
+                results.push(RunResult {
+                    day,
+                    part,
+                    answer: None,
+                    elapsed_ms: 0.0,
+                    status: RunStatus::NoInput,
+                });
+                continue;
+            }
+
+            eprintln!("running day-{day:02} part{part}");
+            let output = run(day, part, &input)?;
+            let status = match (output.killed, output.success, output.is_todo()) {
+                (Some(Killed::Timeout), _, _) => RunStatus::TimedOut,
+                (Some(Killed::Memory), _, _) => RunStatus::OverMemory,
+                (None, true, _) => RunStatus::Ok,
+                (None, false, true) => RunStatus::Todo,
+                (None, false, false) => RunStatus::Failed,
+            };
+            results.push(RunResult {
+                day,
+                part,
+                answer: output.answer.filter(|_| output.success),
+                elapsed_ms: output.elapsed.as_secs_f64() * 1000.0,
+                status,
+            });
+        }
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    } else {
+        print_results(&results);
+    }
+    Ok(())
+}
+
+fn print_results(results: &[RunResult]) {
+    let rows = results
+        .iter()
+        .map(|r| {
+            vec![
+                format!("{:02}", r.day),
+                r.part.to_string(),
+                r.answer.clone().unwrap_or_default(),
+                match r.status {
+                    RunStatus::Ok
+                    | RunStatus::Failed
+                    | RunStatus::TimedOut
+                    | RunStatus::OverMemory => format!("{:.2}ms", r.elapsed_ms),
+                    RunStatus::Todo | RunStatus::NoInput => String::new(),
+                },
+                r.status.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    print_table(&["day", "part", "answer", "time", "status"], &rows);
+
+    let total = results.iter().map(|r| r.elapsed_ms).sum::<f64>();
+    println!("\ntotal: {total:.2}ms");
+}
@@ -0,0 +1,308 @@
+use anyhow::bail;
+use std::env;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::alloc::alloc;
+use crate::bench::{
+    compare, format_ns, parse_divan, parses_nothing, record_rows, BenchArgs, BenchCommands,
+};
+use crate::generate::{render_kind, render_template, template_vars};
+use crate::guesses::guess;
+use crate::input::is_placeholder;
+use crate::limits::{run_with_limits, LimitArgs};
+use crate::register::{add_workspace_member, register_day};
+use crate::run_all::run_all;
+use crate::solve::{build_runner, run_part};
+use crate::status::status;
+use crate::test_all::test_all;
+use crate::util::{
+    day_numbers, exit_on_failure, get_day_string, get_part_string, setup_day_folder,
+};
+use crate::verify::verify;
+use crate::watch::{watch, watched_paths};
+use crate::year::Year;
+use crate::{DayArgs, DayPartOptsArgs, XTaskCommands};
+
+pub fn handle_rust(command: XTaskCommands, year: &Year) -> anyhow::Result<()> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let project_root = &year.root;
+    match command {
+        XTaskCommands::Generate(args) => {
+            let day = args.day_num;
+            let crate_path = year.rust_crate_path(day);
+            render_template(
+                &year.rust_template(),
+                &project_root.join(&crate_path),
+                &template_vars(day),
+                args.force,
+            )?;
+            if let Some(kind) = args.kind {
+                render_kind(
+                    &year.rust_template(),
+                    kind,
+                    &project_root.join(&crate_path),
+                    &template_vars(day),
+                )?;
+            }
+            setup_day_folder(&year.day_dir(day))?;
+
+            add_workspace_member(project_root, &crate_path)?;
+            register_day(
+                &project_root.join("runner"),
+                &format!("../{crate_path}"),
+                day,
+            )?;
+            println!("generated {crate_path}");
+        }
+        XTaskCommands::Run(mut args) => {
+            let input = args.input.resolve(
+                &year.day_dir(args.day_part.day),
+                &year.input_name(args.day_part.part),
+            )?;
+            if args.limits.is_set() {
+                if !args.day_part.opts.is_empty() {
+                    bail!("cargo options do not combine with limits, the runner is always built in release mode");
+                }
+                // under `cargo run` the limits would apply to cargo, not the solution
+                let runner = build_runner(project_root)?;
+                return run_with_limits(
+                    Command::new(runner)
+                        .args(["run", &args.day_part.day.to_string()])
+                        .args([args.day_part.part.to_string(), "--input".to_string()])
+                        .arg(input.as_arg()),
+                    &args.limits,
+                );
+            }
+            let mut runner_args = cargo_runner_opts("run", &mut args.day_part);
+            runner_args.extend(["--input".to_string(), input.as_arg()]);
+            exit_on_failure(
+                Command::new(cargo)
+                    .current_dir(project_root)
+                    .args(runner_args)
+                    .status()?,
+            );
+        }
+        XTaskCommands::List => {
+            exit_on_failure(
+                Command::new(cargo)
+                    .current_dir(project_root)
+                    .args(["run", "--quiet", "--package", "runner", "--", "list"])
+                    .status()?,
+            );
+        }
+        XTaskCommands::RunAll(args) => {
+            let runner = build_runner(project_root)?;
+            run_all(
+                project_root,
+                &args,
+                |day, part| year.input(day, part),
+                |day, part, input| run_part(&runner, day, part, input, &args.limits),
+            )?;
+        }
+        XTaskCommands::Bench(BenchArgs {
+            command: Some(BenchCommands::Compare(args)),
+            ..
+        }) => compare(project_root, "rust", &args)?,
+        XTaskCommands::Bench(args) => {
+            let (Some(day), Some(part)) = (args.day, args.part) else {
+                unreachable!("clap requires day and part without a subcommand");
+            };
+            // divan runs the benches matching any filter, so the parse is timed
+            // too, unless the day hands its input to the parts as it is
+            let mut filters = vec![get_part_string(part)];
+            if !parses_nothing(&project_root.join(year.rust_crate_path(day))) {
+                filters.insert(0, "parse".to_string());
+            }
+            let output = Command::new(cargo)
+                .stdout(Stdio::piped())
+                .current_dir(project_root)
+                .args(["bench", "--bench", &get_day_string(day), "--"])
+                .args(&filters)
+                .output()?;
+
+            if !output.status.success() {
+                eprintln!(
+                    "benchmark failed, results will not be written: {}",
+                    String::from_utf8(output.stderr)?
+                );
+                std::process::exit(1);
+            }
+            let stdout = String::from_utf8(output.stdout)?;
+            print!("{stdout}");
+            let rows = parse_divan(&stdout);
+            for record in record_rows(project_root, "rust", day, &rows, args.save_baseline)? {
+                println!(
+                    "recorded {} {} median {}",
+                    get_day_string(record.day),
+                    record.bench_name(),
+                    format_ns(record.median_ns)
+                );
+            }
+        }
+        XTaskCommands::Build(mut args) => {
+            exit_on_failure(
+                Command::new(cargo)
+                    .current_dir(project_root)
+                    .args(cargo_day("build", &mut args))
+                    .status()?,
+            );
+        }
+        XTaskCommands::Test(args) if args.all => {
+            let passed = test_all(&day_numbers(project_root)?, &args, |day| {
+                cargo_test_day(&cargo, project_root, day, &args.opts)
+            })?;
+            if !passed {
+                std::process::exit(1);
+            }
+        }
+        XTaskCommands::Test(args) => {
+            let day = args.day.expect("clap requires a day without --all");
+            let mut test_args = vec![
+                "test".to_string(),
+                "--package".to_string(),
+                get_day_string(day),
+            ];
+            if let Some(part) = args.part {
+                // the tests of a part live in its `partN` module
+                test_args.push(get_part_string(part));
+            }
+            test_args.extend(args.opts);
+            exit_on_failure(
+                Command::new(cargo)
+                    .current_dir(project_root)
+                    .args(test_args)
+                    .status()?,
+            );
+        }
+        XTaskCommands::Watch(args) => {
+            let parts = args.parts();
+            watch(
+                &watched_paths(year, args.day),
+                Duration::from_millis(args.debounce),
+                || {
+                    if let Err(e) = test_and_run(&cargo, year, args.day, &parts) {
+                        println!("FAIL {e:#}");
+                    }
+                },
+            )?;
+        }
+        XTaskCommands::Alloc(args) => alloc(year, &args)?,
+        XTaskCommands::Status(args) => status(year, "rust", &args, |day| {
+            let (success, stdout, _) = cargo_test_day(&cargo, project_root, day, &[])?;
+            Ok((success, stdout))
+        })?,
+        XTaskCommands::Guess(args) => guess(project_root, &args)?,
+        XTaskCommands::Verify(args) => {
+            let runner = build_runner(project_root)?;
+            verify(project_root, &args, |day, part| {
+                run_part(
+                    &runner,
+                    day,
+                    part,
+                    &year.input(day, part),
+                    &LimitArgs::default(),
+                )
+            })?;
+        }
+        XTaskCommands::Compare(_)
+        | XTaskCommands::Example(_)
+        | XTaskCommands::Fetch(_)
+        | XTaskCommands::Describe(_) => {
+            unreachable!("handled before a language is picked")
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs every test of a day without stopping at the first failing binary,
+/// handing back whether they all passed, the stdout and the stderr.
+pub fn cargo_test_day(
+    cargo: &str,
+    project_root: &Path,
+    day: u8,
+    opts: &[String],
+) -> anyhow::Result<(bool, String, String)> {
+    let output = Command::new(cargo)
+        .current_dir(project_root)
+        .args(["test", "--no-fail-fast", "--package", &get_day_string(day)])
+        .args(opts)
+        .output()?;
+    Ok((
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+    ))
+}
+
+/// One round of watch mode: each part's tests, then the part on the real
+/// input when they pass, finishing with a pass/fail line per part.
+fn test_and_run(cargo: &str, year: &Year, day: u8, parts: &[u8]) -> anyhow::Result<()> {
+    let mut results = vec![];
+    for &part in parts {
+        println!("testing {} part{part}", get_day_string(day));
+        let tests = Command::new(cargo)
+            .current_dir(&year.root)
+            .args([
+                "test",
+                "--quiet",
+                "--package",
+                &get_day_string(day),
+                &get_part_string(part),
+            ])
+            .status()?;
+        results.push((part, tests.success()));
+    }
+
+    let runner = build_runner(&year.root)?;
+    for (part, tests_pass) in results {
+        let name = format!("{} part{part}", get_day_string(day));
+        if !tests_pass {
+            println!("FAIL {name}: tests failed");
+            continue;
+        }
+        let input = year.input(day, part);
+        if std::fs::read_to_string(&input).map_or(true, |contents| is_placeholder(&contents)) {
+            println!("PASS {name}: tests pass, no input to run on yet");
+            continue;
+        }
+        let output = run_part(&runner, day, part, &input, &LimitArgs::default())?;
+        match &output.answer {
+            Some(answer) if output.success => {
+                println!("PASS {name}: {answer} ({:.2?})", output.elapsed)
+            }
+            _ => println!("FAIL {name}: run failed\n{}", output.stderr.trim_end()),
+        }
+    }
+    Ok(())
+}
+
+fn cargo_day(cmd: &str, args: &mut DayArgs) -> Vec<String> {
+    let day = args.day;
+    let opts = &mut args.opts;
+    let day_string = get_day_string(day);
+    let mut args = vec![cmd.to_string(), "--package".to_string(), day_string];
+    args.append(opts);
+    args
+}
+
+fn cargo_runner_opts(cmd: &str, args: &mut DayPartOptsArgs) -> Vec<String> {
+    let day = args.day;
+    let part = args.part;
+    let opts = &mut args.opts;
+    let mut args = vec![
+        cmd.to_string(),
+        "--package".to_string(),
+        "runner".to_string(),
+    ];
+    args.append(opts);
+    args.extend([
+        "--".to_string(),
+        "run".to_string(),
+        day.to_string(),
+        part.to_string(),
+    ]);
+    args
+}
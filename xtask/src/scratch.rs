@@ -0,0 +1,70 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use tempfile::TempDir;
+use toml_edit::{value, Array, DocumentMut};
+
+use crate::generate::{render_template, template_vars};
+
+/// A workspace in a temporary folder with the dependencies of `year` but none
+/// of its days, building against the year's own util crate. `days_glob` is
+/// where its days live, like `day-*` or `day-*/rust`.
+pub fn workspace(year: &str, days_glob: &str) -> TempDir {
+    let year_root = year_root(year);
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+
+    let mut manifest = fs::read_to_string(year_root.join("Cargo.toml"))
+        .unwrap()
+        .parse::<DocumentMut>()
+        .unwrap();
+    manifest["workspace"]["members"] = value(Array::from_iter([days_glob]));
+    let util = manifest["workspace"]["dependencies"]["util"]["path"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    manifest["workspace"]["dependencies"]["util"]["path"] =
+        value(year_root.join(util).to_str().unwrap());
+    fs::write(root.join("Cargo.toml"), manifest.to_string()).unwrap();
+    // the versions the year already builds with, so nothing is downloaded, and
+    // a target folder of its own that outlives the run so later runs are quick
+    if year_root.join("Cargo.lock").is_file() {
+        fs::copy(year_root.join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+    }
+    fs::create_dir(root.join(".cargo")).unwrap();
+    let target = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join(format!("scratch-{year}"));
+    fs::write(
+        root.join(".cargo").join("config.toml"),
+        format!("[build]\ntarget-dir = {:?}\n", target.to_str().unwrap()),
+    )
+    .unwrap();
+    dir
+}
+
+/// Renders the `template` folder of `year` as `day` at `dest`.
+pub fn generate(year: &str, template: &str, day: u8, dest: &Path) {
+    render_template(
+        &year_root(year).join(template),
+        dest,
+        &template_vars(day),
+        false,
+    )
+    .unwrap();
+}
+
+/// The folder of `year` in this repository.
+pub fn year_root(year: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(year)
+}
+
+/// The cargo running the tests, so scratch builds use the same toolchain.
+pub fn cargo() -> String {
+    std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string())
+}
@@ -0,0 +1,102 @@
+use anyhow::{bail, Result};
+use std::{
+    env,
+    io::Read,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::limits::{wait_limited, Killed, LimitArgs};
+
+/// What a single solution run printed, and how long it took.
+pub struct PartOutput {
+    pub success: bool,
+    pub answer: Option<String>,
+    pub stderr: String,
+    pub elapsed: Duration,
+    /// Set when the run went over a limit and was killed.
+    pub killed: Option<Killed>,
+}
+
+impl PartOutput {
+    /// Whether the part panicked on a `todo!()` rather than failing for real.
+    pub fn is_todo(&self) -> bool {
+        !self.success && self.stderr.contains("not yet implemented")
+    }
+}
+
+/// Runs `command` to completion, timing it from spawn to exit and killing
+/// it when it goes over `limits`.
+pub fn timed_output(command: &mut Command, limits: &LimitArgs) -> Result<PartOutput> {
+    let start = Instant::now();
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // drained on their own threads so a chatty solution cannot fill a pipe and stall
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+    let (status, killed) = wait_limited(&mut child, limits)?;
+    let elapsed = start.elapsed();
+
+    Ok(PartOutput {
+        success: status.success() && killed.is_none(),
+        answer: answer_line(&stdout.join().unwrap_or_default()),
+        stderr: stderr.join().unwrap_or_default(),
+        elapsed,
+        killed,
+    })
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buf = vec![];
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        String::from_utf8_lossy(&buf).to_string()
+    })
+}
+
+/// Solutions are free to print debug output, the answer is always the last line.
+pub fn answer_line(stdout: &str) -> Option<String> {
+    stdout
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .map(str::to_string)
+}
+
+/// Builds the runner in release mode once so many parts can be run without going through cargo.
+pub fn build_runner(project_root: &Path) -> Result<PathBuf> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .current_dir(project_root)
+        .args(["build", "--release", "--package", "runner"])
+        .status()?;
+    if !status.success() {
+        bail!("building the runner failed");
+    }
+    Ok(project_root
+        .join("target")
+        .join("release")
+        .join(format!("runner{}", env::consts::EXE_SUFFIX)))
+}
+
+pub fn run_part(
+    runner: &Path,
+    day: u8,
+    part: u8,
+    input: &Path,
+    limits: &LimitArgs,
+) -> Result<PartOutput> {
+    timed_output(
+        Command::new(runner)
+            .args(["run", &day.to_string(), &part.to_string(), "--input"])
+            .arg(input),
+        limits,
+    )
+}
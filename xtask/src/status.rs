@@ -0,0 +1,310 @@
+use anyhow::Result;
+use std::{collections::HashMap, fs, path::Path};
+
+use clap::Args;
+
+use crate::{
+    bench::{format_ns, load_records},
+    input::is_placeholder,
+    test_all::{parse_cargo_test, Outcome},
+    util::{day_numbers, get_day_string},
+    year::Year,
+};
+
+/// Days per row of the calendar, five rows of five cover the whole advent.
+const DAYS_PER_ROW: usize = 5;
+
+/// Fits a part line with the longest bench time, `2 ✓ ✓ ✓ 999.999 ms`, and a gap.
+const CELL_WIDTH: usize = 20;
+
+#[derive(Args)]
+pub struct StatusArgs {
+    /// skip running every day's tests, the slow part of the overview
+    #[arg(long)]
+    no_tests: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Code {
+    Missing,
+    /// `process` is still nothing but the generated `todo!()`
+    Todo,
+    Written,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tests {
+    Skipped,
+    None,
+    Pass,
+    Fail,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Input {
+    Missing,
+    Placeholder,
+    Real,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PartStatus {
+    pub code: Code,
+    pub tests: Tests,
+    pub input: Input,
+    pub median_ns: Option<f64>,
+}
+
+/// Prints a calendar of every day's progress.
+///
+/// `test_day` runs a day's tests and hands back whether they passed and
+/// the `cargo test` output, it is not called with `--no-tests`.
+pub fn status(
+    year: &Year,
+    lang: &str,
+    args: &StatusArgs,
+    test_day: impl Fn(u8) -> Result<(bool, String)>,
+) -> Result<()> {
+    let records = load_records(&year.root)?;
+    let mut days = vec![];
+    for day in day_numbers(&year.root)? {
+        let tests = if args.no_tests {
+            None
+        } else {
+            eprintln!("testing {}", get_day_string(day));
+            Some(test_day(day)?)
+        };
+        let parts = [1, 2].map(|part| {
+            let tests = match &tests {
+                None => Tests::Skipped,
+                Some((success, output)) => {
+                    match parse_test_results(day, output).get(&part).copied() {
+                        Some(tests) => tests,
+                        // it did not get as far as running the tests, so it did not build
+                        None if !success => Tests::Fail,
+                        None => Tests::None,
+                    }
+                }
+            };
+            let median_ns = records
+                .iter()
+                .rfind(|r| r.lang == lang && r.day == day && r.part == part)
+                .map(|r| r.median_ns);
+            PartStatus {
+                code: code_status(&year.root.join(year.rust_crate_path(day)), part),
+                tests,
+                input: input_status(&year.input(day, part)),
+                median_ns,
+            }
+        });
+        days.push((day, parts));
+    }
+
+    print!("{}", render_calendar(&days));
+    println!();
+    println!("each part: code (✓ written, T todo!), tests (✓ pass, ✗ fail, · none, ? skipped),");
+    println!("           input (✓ real, p placeholder, · missing), latest bench median");
+    Ok(())
+}
+
+fn code_status(crate_dir: &Path, part: u8) -> Code {
+    match fs::read_to_string(crate_dir.join("src").join(format!("part{part}.rs"))) {
+        Ok(source) if is_todo(&source) => Code::Todo,
+        Ok(_) => Code::Written,
+        Err(_) => Code::Missing,
+    }
+}
+
+/// Whether `process` still ends in a `todo!()`, the way `generate` leaves
+/// it. A `todo!()` anywhere else is usually an arm that cannot happen.
+fn is_todo(source: &str) -> bool {
+    let Some((_, rest)) = source
+        .split_once("fn process")
+        .and_then(|(_, rest)| rest.split_once('{'))
+    else {
+        return false;
+    };
+    let mut depth = 1;
+    let Some(end) = rest.find(|c| {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => {}
+        }
+        depth == 0
+    }) else {
+        return false;
+    };
+    let body = rest[..end].trim().trim_end_matches(';');
+    let last_statement = body.rsplit_once(';').map_or(body, |(_, last)| last);
+    last_statement.trim_start().starts_with("todo!(")
+}
+
+fn input_status(path: &Path) -> Input {
+    match fs::read_to_string(path) {
+        Ok(contents) if is_placeholder(&contents) => Input::Placeholder,
+        Ok(_) => Input::Real,
+        Err(_) => Input::Missing,
+    }
+}
+
+/// A part passes when every one of its tests did, `todo!()`s count as failures.
+fn parse_test_results(day: u8, output: &str) -> HashMap<u8, Tests> {
+    let mut results = HashMap::new();
+    for case in parse_cargo_test(day, output) {
+        let Some(part) = case.part else {
+            continue;
+        };
+        let tests = results.entry(part).or_insert(Tests::Pass);
+        if matches!(case.outcome, Outcome::Failed | Outcome::Unimplemented) {
+            *tests = Tests::Fail;
+        }
+    }
+    results
+}
+
+fn render_part(part: u8, status: &PartStatus) -> String {
+    let code = match status.code {
+        Code::Missing => "·",
+        Code::Todo => "T",
+        Code::Written => "✓",
+    };
+    let tests = match status.tests {
+        Tests::Skipped => "?",
+        Tests::None => "·",
+        Tests::Pass => "✓",
+        Tests::Fail => "✗",
+    };
+    let input = match status.input {
+        Input::Missing => "·",
+        Input::Placeholder => "p",
+        Input::Real => "✓",
+    };
+    let bench = status.median_ns.map_or("-".to_string(), format_ns);
+    format!("{part} {code} {tests} {input} {bench}")
+}
+
+/// Lays the days out like the advent calendar, each cell holding the day
+/// and a line per part. Days without a folder are left blank.
+fn render_calendar(days: &[(u8, [PartStatus; 2])]) -> String {
+    let Some(last) = days.iter().map(|(day, _)| *day).max() else {
+        return "no days yet, add one with `cargo xtask generate`\n".to_string();
+    };
+    let by_day = days
+        .iter()
+        .map(|(day, parts)| (*day, parts))
+        .collect::<HashMap<_, _>>();
+
+    let mut calendar = String::new();
+    let days_in_calendar = (1..=last).collect::<Vec<_>>();
+    for week in days_in_calendar.chunks(DAYS_PER_ROW) {
+        let mut lines = vec![String::new(); 3];
+        for &day in week {
+            let cells = match by_day.get(&day) {
+                Some(parts) => [
+                    get_day_string(day),
+                    render_part(1, &parts[0]),
+                    render_part(2, &parts[1]),
+                ],
+                None => [
+                    format!("{} ·", get_day_string(day)),
+                    String::new(),
+                    String::new(),
+                ],
+            };
+            for (line, cell) in lines.iter_mut().zip(cells) {
+                line.push_str(&format!("{cell:<CELL_WIDTH$}"));
+            }
+        }
+        for line in lines {
+            calendar.push_str(line.trim_end());
+            calendar.push('\n');
+        }
+        calendar.push('\n');
+    }
+    calendar.trim_end().to_string() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_is_todo() {
+        assert!(is_todo(
+            "pub fn process(input: &str) -> Result<String> {\n    todo!(\"{input}\");\n}\n"
+        ));
+        assert!(!is_todo(
+            "pub fn process(input: &str) -> Result<String> {\n    Ok(match x {\n        1 => todo!(),\n    })\n}\n"
+        ));
+        assert!(is_todo(
+            "pub fn process(input: &str) -> Result<String> {\n    let grid = parse_grid(input);\n    todo!(\"{grid:?}\");\n}\n"
+        ));
+        assert!(!is_todo("fn other() {\n    todo!()\n}\n"));
+    }
+
+    #[test]
+    fn test_parse_test_results() {
+        let output = "
+running 4 tests
+test part1::tests::test_examples ... ok
+test part2::tests::test_examples ... FAILED
+test part2::tests::test_line ... ok
+test helpers::tests::test_parse ... FAILED
+test part1::tests::test_slow ... ignored
+";
+        assert_eq!(
+            parse_test_results(1, output),
+            HashMap::from([(1, Tests::Pass), (2, Tests::Fail)])
+        );
+    }
+
+    #[test]
+    fn test_render_calendar() {
+        let done = PartStatus {
+            code: Code::Written,
+            tests: Tests::Pass,
+            input: Input::Real,
+            median_ns: Some(73_220.0),
+        };
+        let todo = PartStatus {
+            code: Code::Todo,
+            tests: Tests::Fail,
+            input: Input::Placeholder,
+            median_ns: None,
+        };
+        let days = vec![
+            (1, [done, todo]),
+            (
+                7,
+                [
+                    PartStatus {
+                        code: Code::Written,
+                        tests: Tests::None,
+                        input: Input::Missing,
+                        median_ns: None,
+                    },
+                    PartStatus {
+                        code: Code::Missing,
+                        tests: Tests::Skipped,
+                        input: Input::Missing,
+                        median_ns: None,
+                    },
+                ],
+            ),
+        ];
+        assert_eq!(
+            render_calendar(&days),
+            "\
+day-01              day-02 ·            day-03 ·            day-04 ·            day-05 ·
+1 ✓ ✓ ✓ 73.220 µs
+2 T ✗ p -
+
+day-06 ·            day-07
+                    1 ✓ · · -
+                    2 · ? · -
+"
+        );
+    }
+}
@@ -0,0 +1,459 @@
+use anyhow::Result;
+use std::{collections::BTreeMap, fmt, fs, path::PathBuf};
+
+use clap::Args;
+use serde::Serialize;
+
+use crate::{table::print_table, util::get_day_string};
+
+/// How `util::examples::check` fails while a part has no examples to check
+/// yet, which leaves a freshly generated day just as unwritten as a `todo!()`.
+const NO_EXAMPLES: [&str; 2] = ["no examples/expected.toml", "no examples expect an answer"];
+
+#[derive(Args)]
+pub struct TestArgs {
+    #[arg(required_unless_present = "all")]
+    pub day: Option<u8>,
+    /// only this part, go builds and tests one part at a time and does both by default
+    #[arg(short, long)]
+    pub part: Option<u8>,
+    /// test every day, summarize the results per day and part and fail when any test did
+    #[arg(long, conflicts_with = "day")]
+    pub all: bool,
+    /// with --all, also write a JUnit XML report to this file
+    #[arg(long, requires = "all")]
+    pub junit: Option<PathBuf>,
+    /// with --all, also write a JSON report to this file
+    #[arg(long, requires = "all")]
+    pub json: Option<PathBuf>,
+    pub opts: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Passed,
+    Failed,
+    /// The test panicked on a `todo!()` or had no examples to check, the
+    /// part is not written yet.
+    Unimplemented,
+    Ignored,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outcome = match self {
+            Outcome::Passed => "ok",
+            Outcome::Failed => "failed",
+            Outcome::Unimplemented => "unimplemented",
+            Outcome::Ignored => "ignored",
+        };
+        write!(f, "{outcome}")
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct TestCase {
+    pub day: u8,
+    /// `None` for tests outside the `partN` modules.
+    pub part: Option<u8>,
+    pub name: String,
+    pub outcome: Outcome,
+    /// What a failing test printed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+}
+
+/// Tests every day with `test_day`, which hands back whether `cargo test`
+/// succeeded, its stdout and its stderr. Returns whether everything passed,
+/// `todo!()`s aside.
+pub fn test_all(
+    days: &[u8],
+    args: &TestArgs,
+    test_day: impl Fn(u8) -> Result<(bool, String, String)>,
+) -> Result<bool> {
+    let mut cases = vec![];
+    for &day in days {
+        eprintln!("testing {}", get_day_string(day));
+        let (success, stdout, stderr) = test_day(day)?;
+        let mut day_cases = parse_cargo_test(day, &stdout);
+        let explained = day_cases
+            .iter()
+            .any(|c| matches!(c.outcome, Outcome::Failed | Outcome::Unimplemented));
+        if !success && !explained {
+            // cargo failed without a test failing, it did not build or a test binary died
+            day_cases.push(TestCase {
+                day,
+                part: None,
+                name: if day_cases.is_empty() {
+                    "build"
+                } else {
+                    "cargo test"
+                }
+                .to_string(),
+                outcome: Outcome::Failed,
+                output: Some(stderr),
+            });
+        }
+        cases.extend(day_cases.into_iter().filter(|c| match args.part {
+            Some(part) => c.part == Some(part),
+            None => true,
+        }));
+    }
+
+    print_summary(&cases);
+    if let Some(path) = &args.junit {
+        fs::write(path, junit_report(&cases))?;
+        println!("wrote {}", path.display());
+    }
+    if let Some(path) = &args.json {
+        fs::write(path, serde_json::to_string_pretty(&cases)?)?;
+        println!("wrote {}", path.display());
+    }
+    Ok(cases.iter().all(|c| c.outcome != Outcome::Failed))
+}
+
+/// Reads the `test partN::tests::name ... ok` lines of `cargo test`, and the
+/// output it prints for each failure to tell `todo!()`s from real failures.
+pub fn parse_cargo_test(day: u8, stdout: &str) -> Vec<TestCase> {
+    let mut cases = vec![];
+    for line in stdout.lines() {
+        let Some((name, result)) = line
+            .strip_prefix("test ")
+            .and_then(|line| line.split_once(" ... "))
+        else {
+            continue;
+        };
+        let (outcome, output) = match result.trim() {
+            "ok" => (Outcome::Passed, None),
+            "FAILED" => (Outcome::Failed, None),
+            r if r.starts_with("ignored") => (Outcome::Ignored, None),
+            // the test binary died during the test, e.g. on a failed allocation
+            r => (Outcome::Failed, Some(r.to_string())),
+        };
+        let part = name
+            .strip_prefix("part")
+            .and_then(|name| name.split_once("::"))
+            .and_then(|(part, _)| part.parse::<u8>().ok());
+        cases.push(TestCase {
+            day,
+            part,
+            name: name.to_string(),
+            outcome,
+            output,
+        });
+    }
+
+    for (name, output) in failure_outputs(stdout) {
+        if let Some(case) = cases
+            .iter_mut()
+            .find(|c| c.name == name && c.outcome == Outcome::Failed)
+        {
+            if output.contains("not yet implemented")
+                || NO_EXAMPLES.iter().any(|message| output.contains(message))
+            {
+                case.outcome = Outcome::Unimplemented;
+            }
+            case.output = Some(output);
+        }
+    }
+    cases
+}
+
+/// The `---- name stdout ----` sections cargo prints after a failing run.
+fn failure_outputs(stdout: &str) -> Vec<(String, String)> {
+    let mut outputs: Vec<(String, String)> = vec![];
+    for line in stdout.lines() {
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|line| line.strip_suffix(" stdout ----"))
+        {
+            outputs.push((name.to_string(), String::new()));
+        } else if line == "failures:" || line.starts_with("test result:") {
+            // the list of names after the sections is not output
+            if !outputs.is_empty() {
+                outputs.push((String::new(), String::new()));
+            }
+        } else if let Some((_, output)) = outputs.last_mut() {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    outputs
+        .into_iter()
+        .filter(|(name, _)| !name.is_empty())
+        .map(|(name, output)| (name, output.trim().to_string()))
+        .collect()
+}
+
+fn print_summary(cases: &[TestCase]) {
+    let mut groups = BTreeMap::<_, Vec<_>>::new();
+    for case in cases {
+        groups.entry((case.day, case.part)).or_default().push(case);
+    }
+
+    let count =
+        |group: &[&TestCase], outcome| group.iter().filter(|c| c.outcome == outcome).count();
+    let rows = groups
+        .iter()
+        .map(|((day, part), group)| {
+            let failed = count(group, Outcome::Failed);
+            let unimplemented = count(group, Outcome::Unimplemented);
+            let status = if failed > 0 {
+                Outcome::Failed
+            } else if unimplemented > 0 {
+                Outcome::Unimplemented
+            } else {
+                Outcome::Passed
+            };
+            vec![
+                format!("{day:02}"),
+                part.map_or("-".to_string(), |p| p.to_string()),
+                count(group, Outcome::Passed).to_string(),
+                failed.to_string(),
+                unimplemented.to_string(),
+                status.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    print_table(
+        &["day", "part", "passed", "failed", "unimplemented", "status"],
+        &rows,
+    );
+
+    for case in cases.iter().filter(|c| c.outcome == Outcome::Failed) {
+        println!("\n{} {} failed", get_day_string(case.day), case.name);
+        if let Some(output) = &case.output {
+            println!("{output}");
+        }
+    }
+    println!(
+        "\n{} passed, {} failed, {} unimplemented",
+        cases
+            .iter()
+            .filter(|c| c.outcome == Outcome::Passed)
+            .count(),
+        cases
+            .iter()
+            .filter(|c| c.outcome == Outcome::Failed)
+            .count(),
+        cases
+            .iter()
+            .filter(|c| c.outcome == Outcome::Unimplemented)
+            .count(),
+    );
+}
+
+/// One `<testsuite>` per day, unimplemented tests count as skipped.
+fn junit_report(cases: &[TestCase]) -> String {
+    let mut days = cases.iter().map(|c| c.day).collect::<Vec<_>>();
+    days.dedup();
+
+    let count =
+        |cases: &[&TestCase], outcome| cases.iter().filter(|c| c.outcome == outcome).count();
+    let all = cases.iter().collect::<Vec<_>>();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+        all.len(),
+        count(&all, Outcome::Failed),
+        count(&all, Outcome::Unimplemented) + count(&all, Outcome::Ignored),
+    ));
+    for day in days {
+        let suite = cases.iter().filter(|c| c.day == day).collect::<Vec<_>>();
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+            get_day_string(day),
+            suite.len(),
+            count(&suite, Outcome::Failed),
+            count(&suite, Outcome::Unimplemented) + count(&suite, Outcome::Ignored),
+        ));
+        for case in suite {
+            let classname = match case.part {
+                Some(part) => format!("{}.part{part}", get_day_string(day)),
+                None => get_day_string(day),
+            };
+            xml.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                escape(&classname),
+                escape(&case.name)
+            ));
+            let output = escape(case.output.as_deref().unwrap_or_default());
+            match case.outcome {
+                Outcome::Passed => xml.push_str("/>\n"),
+                Outcome::Failed => xml.push_str(&format!(
+                    ">\n      <failure message=\"failed\">{output}</failure>\n    </testcase>\n"
+                )),
+                Outcome::Unimplemented => {
+                    xml.push_str(">\n      <skipped message=\"unimplemented\"/>\n    </testcase>\n")
+                }
+                Outcome::Ignored => {
+                    xml.push_str(">\n      <skipped message=\"ignored\"/>\n    </testcase>\n")
+                }
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rust::cargo_test_day, scratch};
+    use pretty_assertions::assert_eq;
+
+    const OUTPUT: &str = "
+running 4 tests
+test part1::tests::test_examples ... ok
+test part2::tests::test_examples ... FAILED
+test part2::tests::test_process ... FAILED
+test helpers::tests::test_slow ... ignored
+test part2::tests::test_big ... memory allocation of 25769803776 bytes failed
+
+failures:
+
+---- part2::tests::test_examples stdout ----
+
+thread 'part2::tests::test_examples' panicked at src/part2.rs:4:5:
+not yet implemented: 1abc2
+
+---- part2::tests::test_process stdout ----
+
+thread 'part2::tests::test_process' panicked at src/part2.rs:20:9:
+assertion `left == right` failed
+  left: 1
+ right: 2
+
+
+failures:
+    part2::tests::test_examples
+    part2::tests::test_process
+
+test result: FAILED. 1 passed; 2 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+    #[test]
+    fn test_parse_cargo_test() {
+        let cases = parse_cargo_test(3, OUTPUT);
+        assert_eq!(
+            cases
+                .iter()
+                .map(|c| (c.part, c.name.as_str(), c.outcome))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some(1), "part1::tests::test_examples", Outcome::Passed),
+                (
+                    Some(2),
+                    "part2::tests::test_examples",
+                    Outcome::Unimplemented
+                ),
+                (Some(2), "part2::tests::test_process", Outcome::Failed),
+                (None, "helpers::tests::test_slow", Outcome::Ignored),
+                (Some(2), "part2::tests::test_big", Outcome::Failed),
+            ]
+        );
+        assert_eq!(
+            cases[4].output.as_deref(),
+            Some("memory allocation of 25769803776 bytes failed")
+        );
+        assert_eq!(
+            cases[2].output.as_deref(),
+            Some("thread 'part2::tests::test_process' panicked at src/part2.rs:20:9:\nassertion `left == right` failed\n  left: 1\n right: 2")
+        );
+    }
+
+    #[test]
+    fn test_parse_no_examples() {
+        let output = "
+running 1 test
+test part1::tests::test_examples ... FAILED
+
+failures:
+
+---- part1::tests::test_examples stdout ----
+Error: no examples expect an answer for part1 yet, add one with `cargo xtask example add`
+
+failures:
+    part1::tests::test_examples
+
+test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+        assert_eq!(
+            parse_cargo_test(1, output)[0].outcome,
+            Outcome::Unimplemented
+        );
+    }
+
+    /// Generates day 1 into a workspace of its own, next to nothing but the
+    /// real util crate, and runs `test --all` on it with real cargo.
+    #[test]
+    fn test_all_fresh_day() {
+        let dir = scratch::workspace("2023", "day-*");
+        let root = dir.path();
+        scratch::generate("2023", "template", 1, &root.join("day-01"));
+
+        let report = root.join("report.json");
+        let args = TestArgs {
+            day: None,
+            part: None,
+            all: true,
+            junit: None,
+            json: Some(report.clone()),
+            opts: vec![],
+        };
+        let cargo = scratch::cargo();
+        let passed = test_all(&[1], &args, |day| cargo_test_day(&cargo, root, day, &[])).unwrap();
+
+        assert!(passed);
+        let cases: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(report).unwrap()).unwrap();
+        let outcomes = cases
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|case| {
+                (
+                    case["name"].as_str().unwrap(),
+                    case["outcome"].as_str().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert!(outcomes.contains(&("part1::tests::test_examples", "unimplemented")));
+        assert!(outcomes.contains(&("part2::tests::test_examples", "unimplemented")));
+        assert!(outcomes.iter().all(|(_, outcome)| *outcome != "failed"));
+    }
+
+    #[test]
+    fn test_junit_report() {
+        let cases = parse_cargo_test(3, OUTPUT);
+        let xml = junit_report(&cases[..3]);
+        assert_eq!(
+            xml,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites tests="3" failures="1" skipped="1">
+  <testsuite name="day-03" tests="3" failures="1" skipped="1">
+    <testcase classname="day-03.part1" name="part1::tests::test_examples"/>
+    <testcase classname="day-03.part2" name="part2::tests::test_examples">
+      <skipped message="unimplemented"/>
+    </testcase>
+    <testcase classname="day-03.part2" name="part2::tests::test_process">
+      <failure message="failed">thread 'part2::tests::test_process' panicked at src/part2.rs:20:9:
+assertion `left == right` failed
+  left: 1
+ right: 2</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::fs::{self, OpenOptions};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitStatus};
 
 pub fn get_day_string(day: u8) -> String {
     format!("day-{day:02}")
@@ -10,6 +11,13 @@ pub fn get_part_string(part: u8) -> String {
     format!("part{part}")
 }
 
+/// Exits with the child's own code when it failed, so whoever called the xtask sees it.
+pub fn exit_on_failure(status: ExitStatus) {
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
 pub fn setup_day_folder(dir: &PathBuf) -> anyhow::Result<()> {
     fs::create_dir_all(dir)?;
 
@@ -35,3 +43,20 @@ pub fn setup_day_folder(dir: &PathBuf) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Day numbers that have a `day-XX` folder in the project root, in order.
+pub fn day_numbers(project_root: &Path) -> anyhow::Result<Vec<u8>> {
+    let mut days = fs::read_dir(project_root)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("day-"))
+                .and_then(|day| day.parse::<u8>().ok())
+        })
+        .collect::<Vec<_>>();
+    days.sort();
+    Ok(days)
+}
@@ -0,0 +1,77 @@
+use anyhow::{bail, Result};
+use std::path::Path;
+
+use clap::Args;
+
+use crate::{answers::Answers, solve::PartOutput, table::print_table, util::day_numbers};
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// only verify this day, every day with a folder by default
+    day: Option<u8>,
+    /// only verify this part of the day
+    part: Option<u8>,
+}
+
+/// Runs every selected part that has a known answer and reports pass, fail or unknown.
+///
+/// `run` solves a single `(day, part)` against its real input.
+pub fn verify(
+    project_root: &Path,
+    args: &VerifyArgs,
+    run: impl Fn(u8, u8) -> Result<PartOutput>,
+) -> Result<()> {
+    let answers = Answers::load(project_root)?;
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => day_numbers(project_root)?,
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut rows = vec![];
+    let mut failed = 0;
+    for day in days {
+        for &part in &parts {
+            let Some(expected) = answers.get(day, part) else {
+                rows.push(row(day, part, "", "", "unknown"));
+                continue;
+            };
+
+            let output = run(day, part)?;
+            if !output.success {
+                eprintln!("day-{day:02} part{part} failed:\n{}", output.stderr.trim());
+            }
+            let actual = match (&output.answer, output.success) {
+                (Some(answer), true) => answer.as_str(),
+                _ => "error",
+            };
+            let status = if output.success && actual == expected {
+                "pass"
+            } else {
+                failed += 1;
+                "fail"
+            };
+            rows.push(row(day, part, expected, actual, status));
+        }
+    }
+
+    print_table(&["day", "part", "expected", "actual", "status"], &rows);
+
+    if failed > 0 {
+        bail!("{failed} part(s) did not match their known answer");
+    }
+    Ok(())
+}
+
+fn row(day: u8, part: u8, expected: &str, actual: &str, status: &str) -> Vec<String> {
+    vec![
+        format!("{day:02}"),
+        part.to_string(),
+        expected.to_string(),
+        actual.to_string(),
+        status.to_string(),
+    ]
+}
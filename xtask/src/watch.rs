@@ -0,0 +1,167 @@
+use anyhow::Result;
+use std::{
+    path::{Component, Path, PathBuf},
+    sync::mpsc::{Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+use clap::Args;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use crate::year::Year;
+
+#[derive(Args)]
+pub struct WatchArgs {
+    pub day: u8,
+    /// only this part, both parts by default
+    pub part: Option<u8>,
+    /// how long changes have to settle before anything reruns, in milliseconds
+    #[arg(long, default_value_t = 200)]
+    pub debounce: u64,
+}
+
+impl WatchArgs {
+    pub fn parts(&self) -> Vec<u8> {
+        self.part.map_or(vec![1, 2], |part| vec![part])
+    }
+}
+
+/// Everything a change to a day can come from: its crate, its inputs and its
+/// examples. Paths that do not exist yet are left out, they cannot be watched.
+pub fn watched_paths(year: &Year, day: u8) -> Vec<PathBuf> {
+    let mut paths = vec![
+        year.root.join(year.rust_crate_path(day)),
+        year.input(day, 1),
+        year.input(day, 2),
+        year.day_dir(day).join("examples"),
+    ];
+    paths.dedup();
+    paths.retain(|path| path.exists());
+    paths
+}
+
+/// Build output and editor droppings change all the time without the day changing.
+fn is_relevant(path: &Path) -> bool {
+    let ignored_dir = path
+        .components()
+        .any(|c| matches!(c, Component::Normal(name) if name == "target" || name == ".git"));
+    let ignored_file = path.file_name().is_some_and(|name| {
+        let name = name.to_string_lossy();
+        name.ends_with('~') || name.ends_with(".swp") || name.starts_with(".#")
+    });
+    !ignored_dir && !ignored_file
+}
+
+/// Whether `event` changed anything worth rerunning for, reads are not changes.
+fn is_change(event: &Event) -> bool {
+    !matches!(event.kind, EventKind::Access(_)) && event.paths.iter().any(|p| is_relevant(p))
+}
+
+/// Blocks until a change arrives, then keeps swallowing events until
+/// `debounce` passes without one, so a burst of saves counts once.
+/// `None` once the watcher is gone.
+fn next_change(rx: &Receiver<notify::Result<Event>>, debounce: Duration) -> Option<()> {
+    loop {
+        match rx.recv().ok()? {
+            Ok(event) if is_change(&event) => break,
+            Ok(_) => {}
+            Err(e) => eprintln!("watch error: {e}"),
+        }
+    }
+    loop {
+        match rx.recv_timeout(debounce) {
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => return Some(()),
+            Err(RecvTimeoutError::Disconnected) => return None,
+        }
+    }
+}
+
+/// Calls `on_change` once up front and again every time `paths` change.
+pub fn watch(paths: &[PathBuf], debounce: Duration, mut on_change: impl FnMut()) -> Result<()> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    for path in paths {
+        watcher.watch(path, RecursiveMode::Recursive)?;
+    }
+
+    loop {
+        on_change();
+        println!("watching {} paths, ctrl-c to stop", paths.len());
+        // running the day reads its files, which shows up as events too
+        while rx.try_recv().is_ok() {}
+        if next_change(&rx, debounce).is_none() {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year::Layout;
+    use notify::event::{AccessKind, ModifyKind};
+    use pretty_assertions::assert_eq;
+    use std::{fs, sync::mpsc, thread, time::Instant};
+
+    #[test]
+    fn test_watched_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let year = Year {
+            year: 2024,
+            root: root.to_path_buf(),
+            layout: Layout::Split,
+        };
+        fs::create_dir_all(root.join("day-01").join("rust")).unwrap();
+        fs::write(year.input(1, 1), "input1 file!\n").unwrap();
+
+        assert_eq!(
+            watched_paths(&year, 1),
+            vec![
+                root.join("day-01").join("rust"),
+                root.join("day-01").join("input1.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_relevant() {
+        assert!(is_relevant(Path::new("/aoc/2023/day-01/src/part1.rs")));
+        assert!(is_relevant(Path::new("/aoc/2023/day-01/input1.txt")));
+        assert!(!is_relevant(Path::new("/aoc/2023/day-01/target/debug/x")));
+        assert!(!is_relevant(Path::new(
+            "/aoc/2023/day-01/src/.part1.rs.swp"
+        )));
+        assert!(!is_relevant(Path::new("/aoc/2023/day-01/src/part1.rs~")));
+    }
+
+    #[test]
+    fn test_next_change() {
+        let (tx, rx) = mpsc::channel();
+        let event = |kind, path: &str| Ok(Event::new(kind).add_path(PathBuf::from(path)));
+        tx.send(event(EventKind::Access(AccessKind::Any), "/d/input1.txt"))
+            .unwrap();
+        tx.send(event(EventKind::Modify(ModifyKind::Any), "/d/target/x"))
+            .unwrap();
+        tx.send(event(EventKind::Modify(ModifyKind::Any), "/d/src/part1.rs"))
+            .unwrap();
+        let burst = thread::spawn(move || {
+            for _ in 0..3 {
+                thread::sleep(Duration::from_millis(20));
+                tx.send(event(EventKind::Modify(ModifyKind::Any), "/d/src/part1.rs"))
+                    .unwrap();
+            }
+            tx
+        });
+
+        let start = Instant::now();
+        assert_eq!(next_change(&rx, Duration::from_millis(100)), Some(()));
+        assert!(start.elapsed() >= Duration::from_millis(160));
+        // the whole burst was swallowed
+        let tx = burst.join().unwrap();
+        assert!(rx.try_recv().is_err());
+        drop(tx);
+        assert_eq!(next_change(&rx, Duration::from_millis(100)), None);
+    }
+}
@@ -0,0 +1,145 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use crate::util::get_day_string;
+
+/// How a year lays out its days.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layout {
+    /// `day-XX/` is the rust crate itself and every part has its own `inputN.txt`.
+    Flat,
+    /// `day-XX/rust/` and `day-XX/go/partN/` share `day-XX/input1.txt`.
+    Split,
+}
+
+/// One year folder of the repository, like `2023/`.
+pub struct Year {
+    pub year: u16,
+    pub root: PathBuf,
+    pub layout: Layout,
+}
+
+impl Year {
+    /// Picks the year passed with `--year`, or the year folder the current
+    /// directory is in.
+    pub fn resolve(year: Option<u16>) -> Result<Self> {
+        let repo_root = repo_root();
+        let year = match year {
+            Some(year) => year,
+            None => year_from_dir(&repo_root, &env::current_dir()?)
+                .context("not inside a year folder, run from one like `2024/` or pass --year")?,
+        };
+        let layout = match year {
+            2023 => Layout::Flat,
+            2024 => Layout::Split,
+            _ => bail!("there is no layout for {year}, add it to `Year::resolve`"),
+        };
+        Ok(Self {
+            year,
+            root: repo_root.join(year.to_string()),
+            layout,
+        })
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.root.join(get_day_string(day))
+    }
+
+    /// The rust crate of a day, relative to the year root.
+    pub fn rust_crate_path(&self, day: u8) -> String {
+        match self.layout {
+            Layout::Flat => get_day_string(day),
+            Layout::Split => format!("{}/rust", get_day_string(day)),
+        }
+    }
+
+    /// The template `generate` renders a day's rust crate from.
+    pub fn rust_template(&self) -> PathBuf {
+        match self.layout {
+            Layout::Flat => self.root.join("template"),
+            Layout::Split => self.root.join("template-rust"),
+        }
+    }
+
+    pub fn go_template(&self) -> PathBuf {
+        self.root.join("template-go")
+    }
+
+    /// The file name of a part's real input inside its day folder.
+    pub fn input_name(&self, part: u8) -> String {
+        match self.layout {
+            Layout::Flat => format!("input{part}.txt"),
+            Layout::Split => "input1.txt".to_string(),
+        }
+    }
+
+    pub fn input(&self, day: u8, part: u8) -> PathBuf {
+        self.day_dir(day).join(self.input_name(part))
+    }
+
+    pub fn has_go(&self) -> bool {
+        self.layout == Layout::Split
+    }
+}
+
+/// The repository root, which holds one folder per year next to this crate.
+fn repo_root() -> PathBuf {
+    Path::new(&env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(1)
+        .unwrap()
+        .to_path_buf()
+}
+
+/// The year of the folder directly under `repo_root` that `dir` is in.
+fn year_from_dir(repo_root: &Path, dir: &Path) -> Option<u16> {
+    let repo_root = repo_root.canonicalize().ok()?;
+    let dir = dir.canonicalize().ok()?;
+    dir.ancestors()
+        .find(|d| d.parent() == Some(&repo_root))?
+        .file_name()?
+        .to_str()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_year_from_dir() {
+        let repo_root = repo_root();
+        assert_eq!(
+            year_from_dir(&repo_root, &repo_root.join("2023")),
+            Some(2023)
+        );
+        assert_eq!(
+            year_from_dir(&repo_root, &repo_root.join("2024").join("util-rust")),
+            Some(2024)
+        );
+        assert_eq!(year_from_dir(&repo_root, &repo_root), None);
+        assert_eq!(year_from_dir(&repo_root, &repo_root.join("xtask")), None);
+    }
+
+    #[test]
+    fn test_inputs() {
+        let flat = Year {
+            year: 2023,
+            root: PathBuf::from("2023"),
+            layout: Layout::Flat,
+        };
+        let split = Year {
+            year: 2024,
+            root: PathBuf::from("2024"),
+            layout: Layout::Split,
+        };
+        assert_eq!(flat.input(5, 2), Path::new("2023/day-05/input2.txt"));
+        assert_eq!(split.input(5, 2), Path::new("2024/day-05/input1.txt"));
+        assert_eq!(split.rust_crate_path(5), "day-05/rust");
+    }
+}